@@ -2,6 +2,7 @@ use std::fmt::Write;
 use std::path::Path;
 use std::{fs, io};
 
+use cocogitto::conventional::commit::parse_message;
 use cocogitto::COMMITS_METADATA;
 
 use anyhow::{bail, Result};
@@ -48,7 +49,7 @@ pub fn edit_message<P: AsRef<Path>>(
         .filter(|&line| !line.trim_start().starts_with('#'))
         .join("\n");
 
-    let cc = conventional_commit_parser::parse(content.trim())?;
+    let cc = parse_message(content.trim())?;
 
     let footers: Option<String> = if cc.footers.is_empty() {
         None
@@ -2,6 +2,7 @@ mod commit;
 mod mangen;
 
 use std::fs;
+use std::io::Write;
 use std::path::PathBuf;
 
 use cocogitto::conventional::changelog::template::{RemoteContext, Template};
@@ -14,6 +15,7 @@ use cocogitto::{CocoGitto, CommitHook, SETTINGS};
 
 use crate::commit::prepare_edit_message;
 use anyhow::{bail, Context, Result};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use clap::builder::{PossibleValue, PossibleValuesParser};
 use clap::{ArgAction, ArgGroup, Args, CommandFactory, Parser, Subcommand, ValueEnum};
 use clap_complete::{shells, Generator};
@@ -211,11 +213,16 @@ enum Command {
 
         /// Generate the changelog with the given template.
         ///
-        /// Possible values are 'remote', 'full_hash', 'default' or the path to your template.
+        /// Possible values are 'remote', 'full_hash', 'default' or the path to your template,
+        /// optionally prefixed with 'file:'.
         /// If not specified cog will use cog.toml template config or fallback to 'default'.
         #[arg(long, short)]
         template: Option<String>,
 
+        /// Generate the changelog with the Tera template at the given path
+        #[arg(long, conflicts_with_all = ["template", "preview"])]
+        template_file: Option<PathBuf>,
+
         /// Url to use during template generation
         #[arg(long, short, requires_all = ["owner", "repository"])]
         remote: Option<String>,
@@ -227,6 +234,26 @@ enum Command {
         /// Name of the repository used during template generation
         #[arg(long, requires_all = ["owner", "remote"])]
         repository: Option<String>,
+
+        /// Print a colored plain text preview instead of markdown
+        #[arg(long, conflicts_with = "template")]
+        preview: bool,
+
+        /// Only include the commits dated on or after the given day (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<NaiveDate>,
+
+        /// Only include the commits dated on or before the given day (YYYY-MM-DD)
+        #[arg(long)]
+        until: Option<NaiveDate>,
+
+        /// Only include the commits of the given author name or username, releases are kept
+        #[arg(long)]
+        author: Option<String>,
+
+        /// Print each release as soon as it is rendered instead of rendering the whole history at once
+        #[arg(long, conflicts_with_all = ["preview", "since", "until", "author"])]
+        stream: bool,
     },
 
     /// Get current version
@@ -578,29 +605,60 @@ fn main() -> Result<()> {
             pattern,
             at,
             template,
+            template_file,
             remote,
             owner,
             repository,
+            preview,
+            since,
+            until,
+            author,
+            stream,
         } => {
             let cocogitto = CocoGitto::get()?;
 
             let context = RemoteContext::try_new(remote, repository, owner)
+                .map(|context| {
+                    context.with_url_formats(
+                        SETTINGS.changelog.commit_url_format.clone(),
+                        SETTINGS.changelog.compare_url_format.clone(),
+                    )
+                })
                 .or_else(|| SETTINGS.get_template_context());
             let template = template.as_ref().or(SETTINGS.changelog.template.as_ref());
-            let template = if let Some(template) = template {
+            let template = if let Some(path) = template_file {
+                Template::from_file(path, context)?
+            } else if let Some(template) = template {
                 Template::from_arg(template, context)?
             } else {
                 Template::default()
             };
 
             // TODO: fallback to tag here
-            let pattern = pattern.as_deref().unwrap_or("..");
-            let result = match at {
-                Some(at) => cocogitto.get_changelog_at_tag(&at, template)?,
-                None => {
-                    let changelog = cocogitto.get_changelog(pattern, true)?;
-                    changelog.into_markdown(template)?
-                }
+            let pattern = at.as_deref().or(pattern.as_deref()).unwrap_or("..");
+            if stream {
+                let mut stdout = std::io::stdout().lock();
+                cocogitto.write_changelog_stream(pattern, template, &mut stdout)?;
+                writeln!(stdout)?;
+                return Ok(());
+            }
+
+            let mut changelog = cocogitto.get_changelog(pattern, at.is_none())?;
+            if since.is_some() || until.is_some() {
+                let start = since.map_or(NaiveDateTime::MIN, |day| day.and_time(NaiveTime::MIN));
+                let end = until.map_or(NaiveDateTime::MAX, |day| {
+                    day.and_hms_opt(23, 59, 59).expect("valid time")
+                });
+                changelog = changelog.retain_date_window(start, end);
+            }
+            if let Some(author) = author {
+                changelog = changelog.retain_author(&author);
+            }
+
+            let result = if preview {
+                changelog.into_terminal()?
+            } else {
+                changelog.into_markdown(template)?
             };
             println!("{result}");
         }
@@ -214,8 +214,13 @@ impl CocoGitto {
     /// The target version is not created yet when generating the changelog.
     pub fn get_changelog_with_target_version(&self, pattern: &str, tag: Tag) -> Result<Release> {
         let commit_range = self.repository.revwalk(pattern)?;
-        let mut release = Release::try_from(commit_range)?;
+        let mut release =
+            Release::from_commits(commit_range, self.clock.now(), &self.commit_cache)?;
         release.version = OidOf::Tag(tag);
+        if SETTINGS.changelog.diff_stats {
+            release = release.with_diff_stats(&self.repository)?;
+        }
+
         Ok(release)
     }
 
@@ -230,8 +235,13 @@ impl CocoGitto {
             .repository
             .get_commit_range_for_package(pattern, package)?;
 
-        let mut release = Release::try_from(commit_range)?;
+        let mut release =
+            Release::from_commits(commit_range, self.clock.now(), &self.commit_cache)?;
         release.version = OidOf::Tag(tag);
+        if SETTINGS.changelog.diff_stats {
+            release = release.with_diff_stats(&self.repository)?;
+        }
+
         Ok(release)
     }
 
@@ -246,7 +256,8 @@ impl CocoGitto {
             .repository
             .get_commit_range_for_monorepo_global(pattern)?;
 
-        let release = match Release::try_from(commit_range) {
+        let release = Release::from_commits(commit_range, self.clock.now(), &self.commit_cache);
+        let release = match release {
             Ok(mut release) => {
                 release.version = OidOf::Tag(tag);
                 release
@@ -257,6 +268,11 @@ impl CocoGitto {
                 date: Default::default(),
                 commits: vec![],
                 previous: None,
+                tag_message: None,
+                tag_aliases: vec![],
+                diff_stat: None,
+                timestamp: None,
+                pre_releases: vec![],
             },
         };
 
@@ -547,6 +547,8 @@ impl CocoGitto {
 
             let additional_context = ReleaseType::Package(PackageContext {
                 package_name: package_name.as_ref(),
+                scope_filter: package.scope_filter.clone(),
+                include_unscoped: package.include_unscoped,
             });
 
             changelog.write_to_file(&path, template, additional_context)?;
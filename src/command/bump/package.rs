@@ -61,6 +61,8 @@ impl CocoGitto {
             let template = SETTINGS.get_package_changelog_template()?;
             let additional_context = ReleaseType::Package(PackageContext {
                 package_name: opts.package_name,
+                scope_filter: opts.package.scope_filter.clone(),
+                include_unscoped: opts.package.include_unscoped,
             });
             changelog.write_to_file(path, template, additional_context)?;
         }
@@ -1,9 +1,14 @@
 use crate::conventional::changelog::release::Release;
 use crate::conventional::changelog::template::Template;
+use crate::conventional::changelog::{write_releases, ReleaseType};
+use crate::conventional::version::Increment;
+use crate::git::error::TagError;
+use crate::git::tag::{Tag, TagLookUpOptions};
 
-use crate::CocoGitto;
+use crate::{CocoGitto, SETTINGS};
 use anyhow::anyhow;
 use anyhow::Result;
+use std::io::Write;
 
 impl CocoGitto {
     /// ## Get a changelog between two oids
@@ -11,7 +16,53 @@ impl CocoGitto {
     /// - `to` default value:`HEAD` or else first commit
     pub fn get_changelog(&self, pattern: &str, _with_child_releases: bool) -> Result<Release> {
         let commit_range = self.repository.revwalk(pattern)?;
-        Release::try_from(commit_range).map_err(Into::into)
+        let release = Release::from_commits(commit_range, self.clock.now(), &self.commit_cache)?;
+        if SETTINGS.changelog.diff_stats {
+            return Ok(release.with_diff_stats(&self.repository)?);
+        }
+
+        Ok(release)
+    }
+
+    /// ## Write a changelog one release at a time
+    /// Render the releases matching `pattern` from the latest to the oldest and write each one
+    /// to `out` as soon as it is rendered, without holding the whole release chain in memory.
+    /// Settings working across releases, such as deduplication or pre-release folding, have no effect.
+    pub fn write_changelog_stream(
+        &self,
+        pattern: &str,
+        template: Template,
+        out: &mut impl Write,
+    ) -> Result<()> {
+        let commit_range = self.repository.revwalk(pattern)?;
+        let releases =
+            Release::cached_releases_iter(commit_range, self.clock.now(), &self.commit_cache);
+        write_releases(releases, template, out)?;
+        Ok(())
+    }
+
+    /// ## Get a changelog between two oids as JSON
+    /// Serialize the release chain returned by [`CocoGitto::get_changelog`] for other tooling.
+    pub fn get_changelog_json(&self, pattern: &str) -> Result<String> {
+        let changelog = self.get_changelog(pattern, false)?;
+
+        changelog.into_json().map_err(|err| anyhow!(err))
+    }
+
+    /// ## Get the template contexts of a changelog as JSON
+    /// Serialize the contexts the configured template is rendered with, including the
+    /// remote context built from `cog.toml`, for custom template authors.
+    pub fn dump_changelog_context(&self, pattern: &str) -> Result<String> {
+        let changelog = self.get_changelog(pattern, false)?;
+        let context = SETTINGS.get_template_context();
+        let template = match &SETTINGS.changelog.template {
+            Some(template) => Template::from_arg(template, context)?,
+            None => Template::default(),
+        };
+
+        changelog
+            .into_context_json(template, ReleaseType::Standard)
+            .map_err(|err| anyhow!(err))
     }
 
     pub fn get_changelog_at_tag(&self, tag: &str, template: Template) -> Result<String> {
@@ -21,4 +72,56 @@ impl CocoGitto {
             .into_markdown(template)
             .map_err(|err| anyhow!(err))
     }
+
+    /// ## Prepend a release to an existing changelog
+    /// Render the release matching `pattern` and insert it below the `changelog.insert_marker`
+    /// line of `changelog`, replacing the section of the same version if there is one.
+    pub fn prepend_changelog(
+        &self,
+        changelog: &str,
+        pattern: &str,
+        template: Template,
+    ) -> Result<String> {
+        let release = self.get_changelog(pattern, false)?;
+
+        release
+            .prepend_to(changelog, template)
+            .map_err(|err| anyhow!(err))
+    }
+
+    /// ## Preview the changelog of a version that does not exist yet
+    /// Render the commits between the latest tag (or else first commit) and `HEAD`
+    /// as if `HEAD` was tagged with `proposed_version`.
+    pub fn preview_changelog(&self, proposed_version: &str, template: Template) -> Result<String> {
+        let pattern = match self.repository.get_latest_tag(TagLookUpOptions::default()) {
+            Ok(tag) => format!("{tag}.."),
+            Err(TagError::NoTag) => "..".to_string(),
+            Err(err) => return Err(anyhow!(err)),
+        };
+
+        let tag = Tag::from_str(proposed_version, None, None)?;
+        let changelog = self.get_changelog_with_target_version(&pattern, tag)?;
+
+        changelog
+            .into_markdown(template)
+            .map_err(|err| anyhow!(err))
+    }
+
+    /// ## Preview the next release
+    /// Compute the version increment implied by the commits since the latest tag
+    /// and render them as the changelog of the resulting version.
+    pub fn preview_release(&self, template: Template) -> Result<(Increment, String)> {
+        let current_tag = match self.repository.get_latest_tag(TagLookUpOptions::default()) {
+            Ok(tag) => tag,
+            Err(TagError::NoTag) => Tag::default(),
+            Err(err) => return Err(anyhow!(err)),
+        };
+
+        let increment = current_tag.increment_from_commit_history(&self.repository)?;
+        let next = current_tag.bump(increment.into(), &self.repository)?;
+        let next = Tag::create(next.version, None);
+        let changelog = self.preview_changelog(&next.to_string(), template)?;
+
+        Ok((increment, changelog))
+    }
 }
@@ -1,4 +1,4 @@
-use crate::conventional::commit::Commit;
+use crate::conventional::commit::{parse_message, Commit};
 use crate::CocoGitto;
 use crate::CommitHook::CommitMessage;
 use anyhow::Result;
@@ -42,7 +42,7 @@ impl CocoGitto {
         .to_string();
 
         // Validate the message
-        conventional_commit_parser::parse(&conventional_message)?;
+        parse_message(&conventional_message)?;
 
         if opts.add_files {
             self.repository.add_all()?;
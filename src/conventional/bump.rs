@@ -136,6 +136,21 @@ impl Tag {
     }
 
     fn get_version_from_commit_history(&self, repository: &Repository) -> Result<Tag, BumpError> {
+        let increment_type = self.increment_from_commit_history(repository)?;
+
+        Ok(match increment_type {
+            Increment::Major => self.major_bump(),
+            Increment::Minor => self.minor_bump(),
+            Increment::Patch => self.patch_bump(),
+            Increment::NoBump => self.no_bump(),
+        })
+    }
+
+    /// Version increment implied by the commits since the latest tag.
+    pub(crate) fn increment_from_commit_history(
+        &self,
+        repository: &Repository,
+    ) -> Result<Increment, BumpError> {
         let changelog_start_oid = repository
             .get_latest_tag_oid(TagLookUpOptions::default())
             .ok()
@@ -154,14 +169,7 @@ impl Tag {
             .filter_map(Result::ok)
             .collect();
 
-        let increment_type = self.version_increment_from_commit_history(&conventional_commits)?;
-
-        Ok(match increment_type {
-            Increment::Major => self.major_bump(),
-            Increment::Minor => self.minor_bump(),
-            Increment::Patch => self.patch_bump(),
-            Increment::NoBump => self.no_bump(),
-        })
+        self.version_increment_from_commit_history(&conventional_commits)
     }
 
     fn get_package_version_from_commit_history(
@@ -6,9 +6,12 @@ use std::path::PathBuf;
 #[derive(Debug)]
 pub enum ChangelogError {
     TemplateNotFound(PathBuf),
+    InvalidTemplate(PathBuf, tera::Error),
     TeraError(tera::Error),
     WriteError(io::Error),
     SeparatorNotFound(PathBuf),
+    MarkerNotFound(String),
+    InvalidDateFormat(String),
     EmptyRelease,
 }
 
@@ -18,6 +21,12 @@ impl Display for ChangelogError {
             ChangelogError::TemplateNotFound(path) => {
                 writeln!(f, "changelog template not found in {path:?}")
             }
+            ChangelogError::InvalidTemplate(path, err) => {
+                writeln!(
+                    f,
+                    "failed to parse changelog template {path:?}: \n\t{err:?}"
+                )
+            }
             ChangelogError::TeraError(err) => {
                 writeln!(f, "failed to render changelog: \n\t{err:?}")
             }
@@ -29,6 +38,12 @@ impl Display for ChangelogError {
                 "cannot find default separator '- - -' in {}",
                 path.as_path().display()
             ),
+            ChangelogError::MarkerNotFound(marker) => {
+                writeln!(f, "cannot find insert marker '{marker}' in changelog")
+            }
+            ChangelogError::InvalidDateFormat(format) => {
+                writeln!(f, "invalid changelog date format '{format}'")
+            }
             ChangelogError::EmptyRelease => writeln!(f, "No commit found to create a changelog",),
         }
     }
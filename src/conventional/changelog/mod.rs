@@ -1,10 +1,15 @@
-use crate::conventional::changelog::release::Release;
+use crate::conventional::changelog::release::{ChangelogCommit, Release};
 use crate::conventional::changelog::renderer::Renderer;
 
 use crate::conventional::changelog::error::ChangelogError;
 use crate::conventional::changelog::template::{MonoRepoContext, PackageContext, Template};
 
+use crate::git::oid::OidOf;
+use crate::SETTINGS;
+use conventional_commit_parser::commit::CommitType;
+use std::collections::HashMap;
 use std::fs;
+use std::io::Write;
 use std::path::Path;
 
 pub mod error;
@@ -28,19 +33,297 @@ pub enum ReleaseType<'a> {
     Package(PackageContext<'a>),
 }
 
+fn renderer(template: Template) -> Result<Renderer, tera::Error> {
+    let mut renderer = Renderer::try_new(template)?
+        .with_aggregated_footers(SETTINGS.changelog.aggregated_footers.clone())
+        .with_header_style(SETTINGS.changelog.header_style)
+        .with_heading_syntax(SETTINGS.changelog.heading_syntax)
+        .with_scope_format(SETTINGS.changelog.scope_format)
+        .with_footer_style(SETTINGS.changelog.footer_style)
+        .with_scope_counts(SETTINGS.changelog.scope_counts)
+        .with_activity_bar(SETTINGS.changelog.activity_bar)
+        .with_wrap_width(SETTINGS.changelog.wrap_width)
+        .with_breaking_change_scopes(SETTINGS.changelog.breaking_change_scopes)
+        .with_breaking_change_grouping(SETTINGS.changelog.breaking_change_grouping)
+        .with_commit_url_template(SETTINGS.changelog.commit_url_template.clone())
+        .with_type_order(SETTINGS.changelog.type_order)
+        .with_grouping(SETTINGS.changelog.grouping.clone())
+        .with_thousands_separator(SETTINGS.changelog.thousands_separator.clone())
+        .with_dedup_releases(SETTINGS.changelog.dedup_releases)
+        .with_fold_pre_releases(SETTINGS.changelog.fold_pre_releases)
+        .with_section_order(SETTINGS.changelog.section_order.clone())
+        .with_summary_style(SETTINGS.changelog.summary_style)
+        // Validated when loading the settings
+        .with_timezone(
+            SETTINGS
+                .changelog
+                .timezone
+                .as_deref()
+                .and_then(|timezone| timezone.parse().ok()),
+        )
+        .with_commit_line_suffix(SETTINGS.changelog.commit_line_suffix.clone())
+        .with_release_date_format(SETTINGS.changelog.release_date_format.clone())
+        .with_baseline(SETTINGS.changelog.baseline.clone())
+        .with_package_heading_level(SETTINGS.changelog.package_heading_level)
+        .with_unreleased_compare_ref(
+            SETTINGS
+                .changelog
+                .unreleased_compare_ref
+                .clone()
+                .unwrap_or_else(|| "HEAD".to_string()),
+        )
+        .with_deployment_url_template(SETTINGS.changelog.deployment_url_template.clone())
+        .with_same_commit_tags(SETTINGS.changelog.same_commit_tags)
+        .with_contributor_counts(SETTINGS.changelog.contributor_counts)
+        .with_merge_duplicate_sections(SETTINGS.changelog.merge_duplicate_sections)
+        .with_initial_version_label(SETTINGS.changelog.initial_version_label.clone())
+        .with_strip_tag_prefixes(SETTINGS.changelog.strip_tag_prefixes)
+        .with_author_link_fallback(SETTINGS.changelog.author_link_fallback.clone())
+        .with_date_format(SETTINGS.changelog.date_format.clone())
+        .with_milestone_token(SETTINGS.changelog.milestone_token.clone())
+        .with_scoped_commits_first(SETTINGS.changelog.scoped_commits_first)
+        .with_tag_message(SETTINGS.changelog.tag_message)
+        .with_unreleased_label(SETTINGS.changelog.unreleased_label.clone())
+        .with_compare_links(SETTINGS.changelog.flat_compare_links)
+        .with_issue_trackers(SETTINGS.changelog.issue_trackers.clone())
+        .with_commit_labels(SETTINGS.changelog.commit_labels.clone())
+        .with_always_show_types(
+            SETTINGS
+                .changelog
+                .always_show_types
+                .iter()
+                .map(|commit_type| CommitType::from(commit_type.as_str()))
+                .collect(),
+        )
+        .with_internal_types(
+            SETTINGS
+                .changelog
+                .internal_types
+                .iter()
+                .map(|commit_type| CommitType::from(commit_type.as_str()))
+                .collect(),
+        )
+        .with_empty_section_placeholder(SETTINGS.changelog.empty_section_placeholder.clone())
+        .with_empty_release_placeholder(SETTINGS.changelog.empty_release_placeholder.clone())
+        .with_type_abbreviations(
+            SETTINGS
+                .changelog
+                .type_abbreviations
+                .iter()
+                .map(|(commit_type, code)| (CommitType::from(commit_type.as_str()), code.clone()))
+                .collect(),
+        );
+
+    if let Some(author_style) = SETTINGS.changelog.author_style {
+        renderer = renderer.with_author_style(author_style);
+    }
+
+    if let Some(hash_position) = SETTINGS.changelog.hash_position {
+        renderer = renderer.with_hash_position(hash_position);
+    }
+
+    if SETTINGS.changelog.toc {
+        renderer = renderer.with_table_of_contents(SETTINGS.changelog.toc_max_level);
+    }
+
+    if let Some(required_label) = &SETTINGS.changelog.required_label {
+        renderer = renderer
+            .with_required_label(required_label.token.clone(), required_label.label.clone());
+    }
+
+    if !SETTINGS.changelog.author_allowlist.is_empty() {
+        renderer = renderer.with_author_allowlist(
+            SETTINGS
+                .changelog
+                .author_allowlist
+                .iter()
+                .cloned()
+                .collect(),
+        );
+    }
+
+    if !SETTINGS.changelog.trusted_signing_keys.is_empty() {
+        renderer = renderer.with_trusted_keys(
+            SETTINGS
+                .changelog
+                .trusted_signing_keys
+                .iter()
+                .cloned()
+                .collect(),
+        );
+    }
+
+    Ok(renderer)
+}
+
+/// Render the releases one at a time, latest first, writing each one to `out` as soon as it is
+/// rendered. Releases are separated like in [`Release::into_markdown`].
+pub(crate) fn write_releases<'a>(
+    releases: impl Iterator<Item = Result<Release<'a>, ChangelogError>>,
+    template: Template,
+    out: &mut impl Write,
+) -> Result<(), ChangelogError> {
+    // Streamed releases are rendered without their predecessors, none of them is known to be the oldest
+    let mut renderer = renderer(template)?.with_baseline(None);
+    for (idx, release) in releases.enumerate() {
+        if idx > 0 {
+            out.write_all(b"\n- - -\n\n")?;
+        }
+        out.write_all(renderer.render(release?)?.as_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// Combine releases generated separately for several repositories into a single release.
+/// Each commit scope is prefixed with its repository label (`label/scope`, or `label` for
+/// unscoped commits), authors are kept as is. The version and range of the first release are used,
+/// previous releases and diff stats are not merged.
+pub fn merge_releases(releases: Vec<(String, Release)>) -> Result<Release, ChangelogError> {
+    let mut releases = releases.into_iter();
+    let (label, first) = releases.next().ok_or(ChangelogError::EmptyRelease)?;
+    let mut merged = Release {
+        version: first.version,
+        from: first.from,
+        date: first.date,
+        commits: label_commits(&label, first.commits),
+        previous: None,
+        tag_message: first.tag_message,
+        tag_aliases: first.tag_aliases,
+        diff_stat: None,
+        timestamp: first.timestamp,
+        pre_releases: first.pre_releases,
+    };
+
+    for (label, release) in releases {
+        merged.date = merged.date.max(release.date);
+        merged.timestamp = merged.timestamp.max(release.timestamp);
+        merged
+            .commits
+            .extend(label_commits(&label, release.commits));
+    }
+
+    Ok(merged)
+}
+
+fn label_commits<'a>(label: &str, commits: Vec<ChangelogCommit<'a>>) -> Vec<ChangelogCommit<'a>> {
+    commits
+        .into_iter()
+        .map(|mut commit| {
+            let scope = &mut commit.commit.conventional.scope;
+            *scope = match scope.take() {
+                Some(scope) => Some(format!("{label}/{scope}")),
+                None => Some(label.to_string()),
+            };
+            commit
+        })
+        .collect()
+}
+
 impl Release<'_> {
     pub fn into_markdown(self, template: Template) -> Result<String, tera::Error> {
-        let mut renderer = Renderer::try_new(template)?;
+        let mut renderer = renderer(template)?;
         renderer.render(self)
     }
 
+    /// Render the changelog with the commit summaries replaced by the given translations, keyed by
+    /// commit oid or by original summary. Commits without translation keep their summary.
+    pub fn into_translated_markdown(
+        self,
+        template: Template,
+        translations: HashMap<String, String>,
+    ) -> Result<String, tera::Error> {
+        let mut renderer = renderer(template)?.with_summary_translations(translations);
+        renderer.render(self)
+    }
+
+    /// Serialize the whole release chain, previous releases included, to pretty printed JSON.
+    /// Each release carries the number of commits of its contributors under `contributors`.
+    pub fn into_json(self) -> Result<String, tera::Error> {
+        let mut json = tera::to_value(&self)?;
+        let mut release = Some(&self);
+        let mut node = &mut json;
+        while let Some(version) = release {
+            node["contributors"] = tera::to_value(version.contributor_tally())?;
+            release = version.previous.as_deref();
+            node = &mut node["previous"];
+        }
+
+        Ok(format!("{json:#}"))
+    }
+
+    /// Serialize the contexts passed to the template engine when rendering the release chain,
+    /// one per rendered release from the latest to the oldest, remote and monorepo fields included.
+    pub fn into_context_json(
+        self,
+        template: Template,
+        kind: ReleaseType,
+    ) -> Result<String, tera::Error> {
+        let renderer = renderer(template)?;
+        let mut renderer = match kind {
+            ReleaseType::Standard => renderer,
+            ReleaseType::MonoRepo(context) => renderer.with_monorepo_context(context),
+            ReleaseType::Package(context) => renderer.with_package_context(context),
+        };
+
+        let contexts = renderer.render_contexts(self)?;
+        Ok(format!("{:#}", tera::Value::from(contexts)))
+    }
+
+    /// Render the changelog as colored plain text to preview it in a terminal.
+    pub fn into_terminal(self) -> Result<String, tera::Error> {
+        renderer(Template::default())?.render_terminal(self)
+    }
+
+    /// Render a sorted list of the contributors across the whole release chain.
+    pub fn into_contributors(self, template: Template) -> Result<String, tera::Error> {
+        Ok(renderer(template)?.render_contributors(self))
+    }
+
+    /// Render the release chain as standalone changelog documents of `per_page` releases each,
+    /// latest releases first.
+    pub fn into_paginated_markdown(
+        self,
+        template: Template,
+        per_page: usize,
+    ) -> Result<Vec<String>, tera::Error> {
+        renderer(template)?.render_paginated(self, per_page)
+    }
+
+    /// Render a standalone changelog document per commit type across the whole release chain.
+    pub fn into_markdown_by_type(
+        self,
+        template: Template,
+    ) -> Result<HashMap<CommitType, String>, tera::Error> {
+        renderer(template)?.render_by_type(self)
+    }
+
+    /// Render the release and insert it below the `changelog.insert_marker` line of an
+    /// existing changelog, previous releases are left untouched. If a section for the
+    /// same version already exists it is replaced instead of duplicated.
+    pub fn prepend_to(self, changelog: &str, template: Template) -> Result<String, ChangelogError> {
+        let version = match &self.version {
+            OidOf::Tag(tag) => Some(tag.version.to_string()),
+            OidOf::Head(_) => Some(SETTINGS.changelog.unreleased_label.clone()),
+            OidOf::Other(_) | OidOf::FirstCommit(_) => None,
+        };
+
+        let section = renderer(template)?.render(self)?;
+        insert_release(
+            changelog,
+            &section,
+            version.as_deref(),
+            &SETTINGS.changelog.insert_marker,
+        )
+    }
+
     pub fn write_to_file<S: AsRef<Path>>(
         self,
         path: S,
         template: Template,
         kind: ReleaseType,
     ) -> Result<(), ChangelogError> {
-        let renderer = Renderer::try_new(template)?;
+        let renderer = renderer(template)?;
 
         let mut renderer = match kind {
             ReleaseType::Standard => renderer,
@@ -72,3 +355,250 @@ impl Release<'_> {
         }
     }
 }
+
+fn insert_release(
+    changelog: &str,
+    section: &str,
+    version: Option<&str>,
+    marker: &str,
+) -> Result<String, ChangelogError> {
+    let section = format!("{}\n\n{CHANGELOG_SEPARATOR}\n", section.trim_end());
+
+    if let Some((start, end)) = version.and_then(|version| find_release(changelog, version)) {
+        return Ok([&changelog[..start], &section, &changelog[end..]].concat());
+    }
+
+    let mut offset = 0;
+    for line in changelog.split_inclusive('\n') {
+        offset += line.len();
+        if line.trim() == marker {
+            let newline = if line.ends_with('\n') { "" } else { "\n" };
+            return Ok([
+                &changelog[..offset],
+                newline,
+                &section,
+                &changelog[offset..],
+            ]
+            .concat());
+        }
+    }
+
+    Err(ChangelogError::MarkerNotFound(marker.to_string()))
+}
+
+/// Byte range of the release section whose heading mentions `version`, up to and
+/// including the separator line closing it.
+fn find_release(changelog: &str, version: &str) -> Option<(usize, usize)> {
+    let mut offset = 0;
+    let mut start = None;
+
+    for line in changelog.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+
+        match start {
+            None if line.starts_with('#') && mentions_version(line, version) => {
+                start = Some(line_start)
+            }
+            Some(start) if line.trim() == CHANGELOG_SEPARATOR => return Some((start, offset)),
+            _ => {}
+        }
+    }
+
+    start.map(|start| (start, changelog.len()))
+}
+
+fn mentions_version(line: &str, version: &str) -> bool {
+    line.match_indices(version).any(|(idx, _)| {
+        let before = line[..idx].chars().next_back();
+        let after = line[idx + version.len()..].chars().next();
+        !before.is_some_and(|c| c.is_ascii_digit() || c == '.')
+            && !after.is_some_and(|c| c.is_alphanumeric() || matches!(c, '.' | '-' | '+'))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::insert_release;
+    use crate::conventional::changelog::error::ChangelogError;
+    use crate::conventional::changelog::release::Release;
+    use crate::conventional::changelog::template::Template;
+    use crate::test_helpers::{commit, git_init_no_gpg};
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use sealed_test::prelude::*;
+    use std::fs;
+
+    const MARKER: &str = "<!-- cog:insert -->";
+
+    #[test]
+    fn should_insert_release_below_marker() -> anyhow::Result<()> {
+        // Arrange
+        let changelog = indoc! {"
+            # Changelog
+            <!-- cog:insert -->
+
+            ## 1.0.0 - 2024-01-01
+            - first
+
+            - - -
+        "};
+
+        // Act
+        let changelog = insert_release(
+            changelog,
+            "## 1.1.0 - 2024-02-01\n- second\n\n\n",
+            Some("1.1.0"),
+            MARKER,
+        )?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {"
+                # Changelog
+                <!-- cog:insert -->
+                ## 1.1.0 - 2024-02-01
+                - second
+
+                - - -
+
+                ## 1.0.0 - 2024-01-01
+                - first
+
+                - - -
+            "}
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_replace_existing_release_section() -> anyhow::Result<()> {
+        // Arrange
+        let changelog = indoc! {"
+            # Changelog
+            <!-- cog:insert -->
+            ## [v1.1.0](https://example.com) - 2024-02-01
+            - second
+
+            - - -
+
+            ## v1.1.0-rc.1 - 2024-01-15
+            - candidate
+
+            - - -
+        "};
+
+        // Act
+        let changelog = insert_release(
+            changelog,
+            "## [v1.1.0](https://example.com) - 2024-02-02\n- second\n- third\n",
+            Some("1.1.0"),
+            MARKER,
+        )?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {"
+                # Changelog
+                <!-- cog:insert -->
+                ## [v1.1.0](https://example.com) - 2024-02-02
+                - second
+                - third
+
+                - - -
+
+                ## v1.1.0-rc.1 - 2024-01-15
+                - candidate
+
+                - - -
+            "}
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_add_newline_after_marker_on_last_line() -> anyhow::Result<()> {
+        // Act
+        let changelog = insert_release(
+            "# Changelog\n<!-- cog:insert -->",
+            "## 1.0.0\n- first\n",
+            Some("1.0.0"),
+            MARKER,
+        )?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            "# Changelog\n<!-- cog:insert -->\n## 1.0.0\n- first\n\n- - -\n"
+        );
+
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_replace_the_unreleased_section_with_a_custom_label() -> anyhow::Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write(
+            "cog.toml",
+            indoc! {r#"
+                [changelog]
+                unreleased_label = "Upcoming"
+            "#},
+        )?;
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        let changelog = indoc! {"
+            # Changelog
+            <!-- cog:insert -->
+            ## Upcoming (abc1234..def5678)
+            - stale
+
+            - - -
+        "};
+        let release = Release::try_from(repo.revwalk("..")?)?;
+
+        // Act
+        let changelog = release.prepend_to(changelog, Template::default())?;
+
+        // Assert
+        assert!(changelog.contains("\n## Upcoming ("));
+        assert!(changelog.contains("feature 1"));
+        assert!(!changelog.contains("stale"));
+        assert_eq!(changelog.matches("- - -").count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_fail_without_marker() {
+        // Act
+        let result = insert_release("# Changelog\n", "## 1.0.0\n", Some("1.0.0"), MARKER);
+
+        // Assert
+        assert!(matches!(result, Err(ChangelogError::MarkerNotFound(_))));
+    }
+
+    #[test]
+    fn should_serialize_the_contributors_of_each_release() -> anyhow::Result<()> {
+        // Arrange
+        let mut previous = Release::fixture();
+        previous.commits.truncate(1);
+        let mut release = Release::fixture();
+        release.previous = Some(Box::new(previous));
+
+        // Act
+        let json = release.into_json()?;
+
+        // Assert
+        assert!(json.contains(
+            "  \"contributors\": {\n    \"James Delleck\": 1,\n    \"Paul Delafosse\": 2\n  }"
+        ));
+        assert!(json.contains("    \"contributors\": {\n      \"Paul Delafosse\": 1\n    }"));
+        Ok(())
+    }
+}
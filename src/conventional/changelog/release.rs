@@ -1,15 +1,27 @@
-use chrono::{NaiveDateTime, Utc};
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
 use conventional_commit_parser::commit::Footer;
 use serde::Serialize;
 
-use crate::conventional::commit::Commit;
+use crate::conventional::commit::{Commit, ConventionalCommitError};
+use crate::git::diff::DiffStat;
+use crate::git::error::Git2Error;
 use crate::git::oid::OidOf;
+use crate::git::repository::Repository;
+use crate::git::rev::cache::{tag_aliases, tag_message, CommitCache};
 use crate::git::rev::CommitIter;
-use crate::settings;
+use crate::git::signature::CommitSignature;
+use crate::settings::{CommitOrder, RevertHandling};
+use crate::{settings, SETTINGS};
 use colored::Colorize;
 
 use crate::conventional::changelog::error::ChangelogError;
+use git2::{Commit as Git2Commit, Oid};
 use log::warn;
+use once_cell::sync::Lazy;
+use regex::{Regex, RegexSet};
 
 #[derive(Debug, Serialize)]
 pub struct Release<'a> {
@@ -18,12 +30,51 @@ pub struct Release<'a> {
     pub date: NaiveDateTime,
     pub commits: Vec<ChangelogCommit<'a>>,
     pub previous: Option<Box<Release<'a>>>,
+    #[serde(skip)]
+    pub tag_message: Option<String>,
+    #[serde(skip)]
+    pub tag_aliases: Vec<String>,
+    #[serde(skip)]
+    pub diff_stat: Option<DiffStat>,
+    /// Commit date of the release version, `date` is kept naive for rendering.
+    #[serde(skip)]
+    pub timestamp: Option<DateTime<Utc>>,
+    /// Pre-releases folded into this release, from the latest to the oldest.
+    #[serde(skip)]
+    pub pre_releases: Vec<PreRelease>,
+}
+
+/// A commit author or co-author of a release, see [`Release::contributors`].
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct Contributor {
+    pub name: String,
+    pub username: Option<String>,
+    pub email: Option<String>,
+}
+
+/// A pre-release version folded into the next release, see [`Release::fold_pre_releases`].
+#[derive(Debug, Clone, Serialize)]
+pub struct PreRelease {
+    pub tag: String,
+    pub commits: usize,
 }
 
 impl TryFrom<CommitIter<'_>> for Release<'_> {
     type Error = ChangelogError;
 
     fn try_from(commits: CommitIter<'_>) -> Result<Self, Self::Error> {
+        Self::from_commits(commits, Utc::now(), &CommitCache::default())
+    }
+}
+
+impl Release<'_> {
+    /// Build the release chain of the given commits, dated with `now`. Commits already parsed
+    /// in `cache` are not parsed again.
+    pub(crate) fn from_commits(
+        commits: CommitIter<'_>,
+        now: DateTime<Utc>,
+        cache: &CommitCache,
+    ) -> Result<Self, ChangelogError> {
         let mut releases = vec![];
         let mut commit_iter = commits.into_iter().rev().peekable();
 
@@ -38,68 +89,598 @@ impl TryFrom<CommitIter<'_>> for Release<'_> {
                 release_commits.push((oid, commit));
             }
 
+            // The version is the newest commit, `commit_order` only applies to the rendered commits
             release_commits.reverse();
             releases.push(release_commits);
         }
 
+        let excluded_summaries = Self::excluded_summaries();
+        let branches = if SETTINGS.changelog.branch_origin {
+            Self::branch_origins(&releases)
+        } else {
+            HashMap::new()
+        };
+
         let mut current = None;
 
         for release in releases {
-            let next = Release {
-                version: release.first().unwrap().0.clone(),
-                from: current
-                    .as_ref()
-                    .map(|current: &Release| current.version.clone())
-                    .unwrap_or(release.last().unwrap().0.clone()),
-                date: Utc::now().naive_local(),
-                commits: release
-                    .iter()
-                    .filter_map(|(_, commit)| match Commit::from_git_commit(commit) {
-                        Ok(commit) => {
-                            if !commit.should_omit() {
-                                Some(ChangelogCommit::from(commit))
-                            } else {
-                                None
+            let from = current
+                .as_ref()
+                .map(|current: &Release| current.version.clone())
+                .unwrap_or(release.last().unwrap().0.clone());
+            let mut next = Self::from_release_commits(
+                release,
+                from,
+                now,
+                &excluded_summaries,
+                &branches,
+                cache,
+            );
+            next.previous = current.map(Box::new);
+            current = Some(next);
+        }
+
+        current.ok_or(ChangelogError::EmptyRelease)
+    }
+
+    /// Build the releases of the given commits one at a time, from the latest to the oldest.
+    /// Unlike [`Release::try_from`], the releases are not linked through `previous` and merged
+    /// branch names are only resolved within each release.
+    pub fn releases_iter<'a>(
+        commits: CommitIter<'a>,
+    ) -> impl Iterator<Item = Result<Self, ChangelogError>> + 'a
+    where
+        Self: 'a,
+    {
+        Self::cached_releases_iter(commits, Utc::now(), CommitCache::default())
+    }
+
+    /// [`Self::releases_iter`] dated with `now`, commits already parsed in `cache` are not
+    /// parsed again.
+    pub(crate) fn cached_releases_iter<'a>(
+        commits: CommitIter<'a>,
+        now: DateTime<Utc>,
+        cache: impl Borrow<CommitCache> + 'a,
+    ) -> impl Iterator<Item = Result<Self, ChangelogError>> + 'a
+    where
+        Self: 'a,
+    {
+        let excluded_summaries = Self::excluded_summaries();
+        let mut commits = commits.into_iter().peekable();
+        let empty = commits
+            .peek()
+            .is_none()
+            .then_some(Err(ChangelogError::EmptyRelease));
+
+        empty.into_iter().chain(std::iter::from_fn(move || {
+            let mut release = vec![commits.next()?];
+            while let Some((oid, _)) = commits.peek() {
+                if matches!(oid, OidOf::Tag(_)) {
+                    break;
+                }
+                release.extend(commits.next());
+            }
+
+            let from = match commits.peek() {
+                Some((previous, _)) => previous.clone(),
+                None => release.last().unwrap().0.clone(),
+            };
+            let branches = if SETTINGS.changelog.branch_origin {
+                Self::branch_origins(std::slice::from_ref(&release))
+            } else {
+                HashMap::new()
+            };
+
+            Some(Ok(Self::from_release_commits(
+                release,
+                from,
+                now,
+                &excluded_summaries,
+                &branches,
+                cache.borrow(),
+            )))
+        }))
+    }
+
+    // Patterns are validated when loading the settings
+    fn excluded_summaries() -> RegexSet {
+        RegexSet::new(&SETTINGS.changelog.exclude_summary_patterns)
+            .unwrap_or_else(|_| RegexSet::empty())
+    }
+
+    // A single release of the chain from its commits, latest first, `previous` is left unset
+    fn from_release_commits(
+        mut release_commits: Vec<(OidOf, Git2Commit)>,
+        from: OidOf,
+        now: DateTime<Utc>,
+        excluded_summaries: &RegexSet,
+        branches: &HashMap<Oid, String>,
+        cache: &CommitCache,
+    ) -> Self {
+        let version = release_commits.first().unwrap().0.clone();
+        let timestamp = release_commits
+            .first()
+            .and_then(|(_, commit)| DateTime::from_timestamp(commit.time().seconds(), 0));
+        Self::order_ties(&mut release_commits);
+        let mut release = Release {
+            tag_message: match &version {
+                OidOf::Tag(tag) => tag_message(tag),
+                _ => None,
+            },
+            tag_aliases: match &version {
+                OidOf::Tag(tag) => tag_aliases(tag),
+                _ => vec![],
+            },
+            diff_stat: None,
+            timestamp,
+            version,
+            from,
+            date: now.naive_local(),
+            commits: release_commits
+                .iter()
+                .filter(|(_, commit)| {
+                    !commit
+                        .summary()
+                        .is_some_and(|summary| excluded_summaries.is_match(summary))
+                })
+                // Release boundaries are already set, a skipped tagged merge commit still ends its release
+                .filter(|(_, commit)| {
+                    !(SETTINGS.changelog.skip_merge_commits && commit.parent_count() > 1)
+                })
+                .filter_map(|(_, commit)| match cache.parse(commit) {
+                    Ok(mut parsed) => {
+                        let changelog_footer = parsed.changelog_footer().map(str::to_string);
+                        match changelog_footer {
+                            Some(footer) if footer.eq_ignore_ascii_case("skip") => None,
+                            _ if parsed.should_omit() => None,
+                            footer => {
+                                if let Some(summary) = footer {
+                                    parsed.conventional.summary = summary;
+                                }
+
+                                Some(ChangelogCommit {
+                                    commit_signature: CommitSignature::from_commit(commit),
+                                    author_email: commit.author().email().map(str::to_string),
+                                    branch: branches.get(&commit.id()).cloned(),
+                                    ..ChangelogCommit::from(parsed)
+                                })
                             }
                         }
-                        Err(err) => {
-                            let err = err.to_string().red();
-                            warn!("{}", err);
-                            None
-                        }
+                    }
+                    Err(err)
+                        if SETTINGS.changelog.include_non_conventional
+                            && matches!(*err, ConventionalCommitError::CommitFormat { .. }) =>
+                    {
+                        Some(ChangelogCommit {
+                            commit_signature: CommitSignature::from_commit(commit),
+                            author_email: commit.author().email().map(str::to_string),
+                            branch: branches.get(&commit.id()).cloned(),
+                            ..ChangelogCommit::from(Commit::from_non_conventional_git_commit(
+                                commit,
+                            ))
+                        })
+                    }
+                    Err(err) => {
+                        let err = err.to_string().red();
+                        warn!("{}", err);
+                        None
+                    }
+                })
+                .collect(),
+            previous: None,
+            pre_releases: vec![],
+        };
+
+        if SETTINGS.changelog.commit_order == CommitOrder::OldestFirst {
+            release.commits.reverse();
+        }
+
+        if SETTINGS.changelog.revert_handling != RevertHandling::Keep {
+            Self::handle_reverts(&mut release.commits, SETTINGS.changelog.revert_handling);
+        }
+
+        release
+    }
+
+    // Best effort: the commits merged by a `Merge branch '<name>'` commit are the first parent
+    // chain of its second parent, down to the mainline of the range. Older merges are handled
+    // first so that commits of nested branches keep the innermost branch name.
+    fn branch_origins(releases: &[Vec<(OidOf, Git2Commit)>]) -> HashMap<Oid, String> {
+        static MERGE_BRANCH: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^Merge (?:remote-tracking )?branch '([^']+)'").expect("valid regex")
+        });
+
+        let mut branches = HashMap::new();
+        let Some((_, head)) = releases.last().and_then(|release| release.first()) else {
+            return branches;
+        };
+
+        let in_range: HashSet<Oid> = releases.iter().flatten().map(|(_, c)| c.id()).collect();
+        let mainline: HashSet<Oid> =
+            std::iter::successors(Some(head.clone()), |c| c.parent(0).ok())
+                .map(|commit| commit.id())
+                .take_while(|oid| in_range.contains(oid))
+                .collect();
+
+        let merges = releases
+            .iter()
+            .flat_map(|release| release.iter().rev())
+            .map(|(_, commit)| commit)
+            .filter(|commit| commit.parent_count() > 1);
+
+        for merge in merges {
+            let Some(branch) = merge
+                .summary()
+                .and_then(|summary| MERGE_BRANCH.captures(summary))
+                .map(|captures| captures[1].to_string())
+            else {
+                continue;
+            };
+
+            let merged = std::iter::successors(merge.parent(1).ok(), |c| c.parent(0).ok())
+                .map(|commit| commit.id())
+                .take_while(|oid| {
+                    in_range.contains(oid) && !mainline.contains(oid) && !branches.contains_key(oid)
+                })
+                .collect::<Vec<_>>();
+
+            for oid in merged {
+                branches.insert(oid, branch.clone());
+            }
+        }
+
+        branches
+    }
+
+    // Reverts are matched with the commit named in their `This reverts commit <sha>` body,
+    // reverts of commits outside the release are kept as is
+    fn handle_reverts(commits: &mut Vec<ChangelogCommit>, handling: RevertHandling) {
+        let pairs: Vec<(usize, usize)> = commits
+            .iter()
+            .enumerate()
+            .filter_map(|(revert, commit)| {
+                let oid = commit.reverted_oid()?;
+                commits
+                    .iter()
+                    .position(|reverted| reverted.commit.oid.starts_with(oid))
+                    .map(|reverted| (revert, reverted))
+            })
+            .collect();
+
+        let mut dropped = HashSet::new();
+        for (revert, reverted) in pairs {
+            dropped.insert(revert);
+            match handling {
+                RevertHandling::StrikeThrough => commits[reverted].reverted = true,
+                _ => {
+                    dropped.insert(reverted);
+                }
+            }
+        }
+
+        *commits = std::mem::take(commits)
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| !dropped.contains(idx))
+            .map(|(_, commit)| commit)
+            .collect();
+    }
+
+    // Commits sharing a timestamp follow the first parent of the previous commit, other branches
+    // are ordered by oid and children still come before their parents. Regenerating the same range
+    // renders the commits in the same order whatever the walk order of unrelated branches.
+    fn order_ties(commits: &mut [(OidOf, Git2Commit)]) {
+        for ties in commits.chunk_by_mut(|(_, a), (_, b)| a.time().seconds() == b.time().seconds())
+        {
+            let mut pending = ties.to_vec();
+            let mut first_parent = None;
+            for slot in ties.iter_mut() {
+                let next = pending
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (_, commit))| {
+                        !pending.iter().any(|(_, child)| {
+                            child.parent_ids().any(|parent| parent == commit.id())
+                        })
                     })
-                    .collect(),
-                previous: current.map(Box::new),
+                    .min_by_key(|(_, (_, commit))| (Some(commit.id()) != first_parent, commit.id()))
+                    .map_or(0, |(idx, _)| idx);
+                *slot = pending.remove(next);
+                first_parent = slot.1.parent_id(0).ok();
+            }
+        }
+    }
+
+    /// Compute the diff summary of every release in the chain.
+    pub(crate) fn with_diff_stats(mut self, repo: &Repository) -> Result<Self, Git2Error> {
+        self.diff_stat =
+            Some(repo.diff_stat(self.commits.iter().map(|commit| commit.commit.oid.as_str()))?);
+
+        if let Some(previous) = self.previous.take() {
+            self.previous = Some(Box::new(previous.with_diff_stats(repo)?));
+        }
+
+        Ok(self)
+    }
+
+    /// Drop the releases of the chain sharing their tag with another release,
+    /// keeping the one with the most commits.
+    pub(crate) fn dedup_versions(self) -> Self {
+        let mut releases: Vec<Self> = vec![];
+        for release in self.into_chain() {
+            let duplicate = match &release.version {
+                OidOf::Tag(tag) => releases.iter().position(|kept| {
+                    matches!(&kept.version, OidOf::Tag(kept_tag) if kept_tag.to_string() == tag.to_string())
+                }),
+                _ => None,
             };
 
-            current = Some(next);
+            match duplicate {
+                Some(idx) if releases[idx].commits.len() < release.commits.len() => {
+                    releases[idx] = release
+                }
+                Some(_) => {}
+                None => releases.push(release),
+            }
         }
 
-        current.ok_or(ChangelogError::EmptyRelease)
+        Self::from_chain(releases)
+    }
+
+    /// Fold the pre-release versions of the chain (`1.1.0-rc.1`...) into the next release,
+    /// which then spans from the previous stable version. The folded tags are kept as
+    /// `pre_releases`, diff stats are summed.
+    pub(crate) fn fold_pre_releases(self) -> Self {
+        let mut releases: Vec<Self> = vec![];
+        for mut release in self.into_chain() {
+            let is_pre_release =
+                matches!(&release.version, OidOf::Tag(tag) if !tag.version.pre.is_empty());
+
+            match releases.last_mut() {
+                Some(next) if is_pre_release => {
+                    next.pre_releases.push(PreRelease {
+                        tag: release.version.to_string(),
+                        commits: release.commits.len(),
+                    });
+                    match SETTINGS.changelog.commit_order {
+                        CommitOrder::NewestFirst => next.commits.append(&mut release.commits),
+                        CommitOrder::OldestFirst => {
+                            next.commits.splice(0..0, release.commits.drain(..));
+                        }
+                    }
+                    next.from = release.from;
+                    next.diff_stat = match (next.diff_stat.take(), release.diff_stat) {
+                        (Some(next), Some(folded)) => Some(DiffStat {
+                            files_changed: next.files_changed + folded.files_changed,
+                            insertions: next.insertions + folded.insertions,
+                            deletions: next.deletions + folded.deletions,
+                            commits: next.commits + folded.commits,
+                        }),
+                        (next, folded) => next.or(folded),
+                    };
+                }
+                _ => releases.push(release),
+            }
+        }
+
+        Self::from_chain(releases)
+    }
+
+    /// Only keep the commits authored or co-authored by one of the given usernames,
+    /// dropping the releases left without commits (the latest release is always kept).
+    pub(crate) fn retain_authors(self, usernames: &HashSet<String>) -> Self {
+        let mut releases = self.into_chain();
+        for release in &mut releases {
+            release.commits.retain(|commit| {
+                let allowed = |username: &str| usernames.contains(username);
+                commit.author_username.is_some_and(allowed)
+                    || commit
+                        .co_authors()
+                        .filter_map(settings::commit_username)
+                        .any(allowed)
+            });
+        }
+
+        Self::from_non_empty_chain(releases)
+    }
+
+    /// Only keep the commits whose author name or resolved username is `author`.
+    /// Unlike [`Self::retain_authors`], every release of the chain is kept, even when left empty.
+    pub fn retain_author(self, author: &str) -> Self {
+        let mut releases = self.into_chain();
+        for release in &mut releases {
+            release.commits.retain(|commit| {
+                commit.commit.author == author || commit.author_username == Some(author)
+            });
+        }
+
+        Self::from_chain(releases)
+    }
+
+    /// Only keep the commits dated within `start` and `end` (inclusive),
+    /// dropping the releases left without commits like [`Self::retain_authors`].
+    pub fn retain_date_window(self, start: NaiveDateTime, end: NaiveDateTime) -> Self {
+        let mut releases = self.into_chain();
+        for release in &mut releases {
+            release
+                .commits
+                .retain(|commit| (start..=end).contains(&commit.commit.date));
+        }
+
+        Self::from_non_empty_chain(releases)
+    }
+
+    /// Split the chain into chains of at most `per_page` releases, latest releases first.
+    pub(crate) fn into_pages(self, per_page: usize) -> Vec<Self> {
+        let mut chain = self.into_chain().into_iter().peekable();
+        let mut pages = vec![];
+        while chain.peek().is_some() {
+            let page = chain.by_ref().take(per_page.max(1)).collect();
+            pages.push(Self::from_chain(page));
+        }
+
+        pages
+    }
+
+    // Releases of the chain from the latest to the oldest, unlinked
+    fn into_chain(self) -> Vec<Self> {
+        let mut chain = vec![];
+        let mut current = Some(self);
+        while let Some(mut release) = current {
+            current = release.previous.take().map(|previous| *previous);
+            chain.push(release);
+        }
+
+        chain
+    }
+
+    // Link the releases holding commits, the latest release is kept when the whole chain is empty
+    fn from_non_empty_chain(mut chain: Vec<Self>) -> Self {
+        let latest = chain.remove(0);
+        chain.retain(|release| !release.commits.is_empty());
+        if !latest.commits.is_empty() || chain.is_empty() {
+            chain.insert(0, latest);
+        }
+
+        Self::from_chain(chain)
+    }
+
+    fn from_chain(chain: Vec<Self>) -> Self {
+        chain
+            .into_iter()
+            .rev()
+            .reduce(|previous, mut release| {
+                release.previous = Some(Box::new(previous));
+                release
+            })
+            .expect("a release chain is never empty")
+    }
+
+    /// Count the commits each contributor took part in, crediting the commit author
+    /// and every `Co-authored-by` footer.
+    pub fn contributor_tally(&self) -> BTreeMap<String, usize> {
+        let mut tally = BTreeMap::new();
+        for commit in &self.commits {
+            *tally.entry(commit.commit.author.clone()).or_default() += 1;
+            for co_author in commit.co_authors() {
+                *tally.entry(co_author.to_string()).or_default() += 1;
+            }
+        }
+
+        tally
+    }
+
+    /// The commit authors and `Co-authored-by` footers of the release, listed once per name
+    /// and sorted by name. Usernames and emails are taken from the first commit providing them.
+    pub fn contributors(&self) -> Vec<Contributor> {
+        let mut contributors: BTreeMap<String, Contributor> = BTreeMap::new();
+        let mut credit = |name: &str, username: Option<&str>, email: Option<&str>| {
+            let contributor = contributors
+                .entry(name.to_string())
+                .or_insert_with(|| Contributor {
+                    name: name.to_string(),
+                    username: None,
+                    email: None,
+                });
+            if contributor.username.is_none() {
+                contributor.username = username.map(str::to_string);
+            }
+            if contributor.email.is_none() {
+                contributor.email = email.map(str::to_string);
+            }
+        };
+
+        for commit in &self.commits {
+            credit(
+                &commit.commit.author,
+                commit.author_username,
+                commit.author_email.as_deref(),
+            );
+            for (name, email) in commit.co_author_identities() {
+                credit(name, settings::commit_username(name), email);
+            }
+        }
+
+        contributors.into_values().collect()
     }
 }
 
 #[derive(Debug)]
 pub struct ChangelogCommit<'a> {
     pub author_username: Option<&'a str>,
+    pub author_email: Option<String>,
+    /// Profile URL of the author, preferred by the templates over the one built from the username.
+    pub author_url: Option<String>,
     pub commit: Commit,
+    pub commit_signature: Option<CommitSignature>,
+    /// Reverted later in the same release, see [`RevertHandling::StrikeThrough`].
+    pub reverted: bool,
+    /// Branch the commit was merged from, see [`Release::branch_origins`].
+    pub branch: Option<String>,
 }
 
-impl From<Commit> for ChangelogCommit<'_> {
-    fn from(commit: Commit) -> Self {
+impl ChangelogCommit<'_> {
+    /// The abbreviated or full oid of the commit reverted by this one, as written by `git revert`.
+    pub fn reverted_oid(&self) -> Option<&str> {
+        let body = self.commit.conventional.body.as_deref()?;
+        let (_, rest) = body.split_once("This reverts commit ")?;
+        let end = rest
+            .find(|c: char| !c.is_ascii_hexdigit())
+            .unwrap_or(rest.len());
+        Some(&rest[..end]).filter(|oid| oid.len() >= 7)
+    }
+
+    /// Names of the co-authors declared in the commit `Co-authored-by` footers.
+    pub fn co_authors(&self) -> impl Iterator<Item = &str> {
+        self.co_author_identities().map(|(name, _email)| name)
+    }
+
+    /// Names and emails of the co-authors declared in the commit `Co-authored-by` footers
+    /// (`Jane Doe <jane@doe.org>`).
+    pub fn co_author_identities(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.commit
+            .conventional
+            .footers
+            .iter()
+            .filter(|footer| footer.token.eq_ignore_ascii_case("co-authored-by"))
+            .map(|footer| match footer.content.split_once('<') {
+                Some((name, email)) => (name.trim(), Some(email.trim_end().trim_end_matches('>'))),
+                None => (footer.content.trim(), None),
+            })
+    }
+}
+
+impl ChangelogCommit<'_> {
+    /// Wrap the commit with a pre-resolved author profile URL, when `None` the templates
+    /// build the URL from the author username and the remote.
+    pub fn from_commit(commit: Commit, author_url: Option<String>) -> Self {
         let author_username = settings::commit_username(&commit.author);
 
         ChangelogCommit {
             author_username,
+            author_email: None,
+            author_url,
             commit,
+            commit_signature: None,
+            reverted: false,
+            branch: None,
         }
     }
 }
 
-#[derive(Serialize)]
+impl From<Commit> for ChangelogCommit<'_> {
+    fn from(commit: Commit) -> Self {
+        let author_url = settings::commit_author_url(&commit.author).map(str::to_string);
+        Self::from_commit(commit, author_url)
+    }
+}
+
+#[derive(Serialize, PartialEq)]
 pub struct ChangelogFooter<'a> {
     token: &'a str,
     content: &'a str,
+    pub(crate) scope: Option<&'a str>,
+    pub(crate) package: Option<&'a str>,
 }
 
 impl<'a> From<&'a Footer> for ChangelogFooter<'a> {
@@ -107,30 +688,41 @@ impl<'a> From<&'a Footer> for ChangelogFooter<'a> {
         Self {
             token: footer.token.as_str(),
             content: footer.content.as_str(),
+            scope: None,
+            package: None,
         }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::fs;
+
     use anyhow::Result;
-    use chrono::NaiveDateTime;
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+    use cmd_lib::run_cmd;
     use conventional_commit_parser::commit::{CommitType, ConventionalCommit, Footer};
     use git2::Oid;
     use indoc::indoc;
     use pretty_assertions::assert_eq;
+    use sealed_test::prelude::*;
     use speculoos::prelude::*;
 
-    use crate::conventional::changelog::release::{ChangelogCommit, Release};
+    use crate::conventional::changelog::merge_releases;
+    use crate::conventional::changelog::release::{ChangelogCommit, Contributor, Release};
     use crate::conventional::changelog::renderer::Renderer;
     use crate::conventional::changelog::template::{
-        MonoRepoContext, PackageBumpContext, PackageContext, RemoteContext, Template, TemplateKind,
+        MonoRepoContext, PackageBumpContext, PackageBumpSummary, PackageContext, RemoteContext,
+        Template, TemplateKind,
     };
     use crate::conventional::commit::Commit;
     use crate::git::oid::OidOf;
     use crate::git::repository::Repository;
+    use crate::git::rev::cache::CommitCache;
 
     use crate::git::tag::Tag;
+    use crate::settings::{BreakingChangeGrouping, Changelog, Settings};
+    use crate::test_helpers::{commit, git_init_no_gpg, git_tag};
 
     #[test]
     fn should_get_a_release() -> anyhow::Result<()> {
@@ -143,6 +735,143 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn should_tally_contributors_with_co_authors() {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[0].commit.conventional.footers.extend(
+            [
+                "Jane Doe <jane@doe.org>",
+                "James Delleck <james@delleck.org>",
+            ]
+            .map(|content| Footer {
+                token: "Co-authored-by".to_string(),
+                content: content.to_string(),
+                ..Default::default()
+            }),
+        );
+
+        // Act
+        let tally = release.contributor_tally();
+
+        // Assert
+        assert_that!(tally.get("Paul Delafosse"))
+            .is_some()
+            .is_equal_to(&2);
+        assert_that!(tally.get("James Delleck"))
+            .is_some()
+            .is_equal_to(&2);
+        assert_that!(tally.get("Jane Doe"))
+            .is_some()
+            .is_equal_to(&1);
+        assert_that!(tally.len()).is_equal_to(3);
+    }
+
+    #[test]
+    fn should_list_contributors_once_with_co_author_emails() {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[0].commit.conventional.footers.extend(
+            [
+                "Jane Doe <jane@doe.org>",
+                "Paul Delafosse <paul@delafosse.org>",
+            ]
+            .map(|content| Footer {
+                token: "Co-authored-by".to_string(),
+                content: content.to_string(),
+                ..Default::default()
+            }),
+        );
+
+        // Act
+        let contributors = release.contributors();
+
+        // Assert
+        assert_that!(contributors).is_equal_to(vec![
+            Contributor {
+                name: "James Delleck".to_string(),
+                username: Some("oknozor".to_string()),
+                email: None,
+            },
+            Contributor {
+                name: "Jane Doe".to_string(),
+                username: None,
+                email: Some("jane@doe.org".to_string()),
+            },
+            Contributor {
+                name: "Paul Delafosse".to_string(),
+                username: Some("oknozor".to_string()),
+                email: Some("paul@delafosse.org".to_string()),
+            },
+        ]);
+    }
+
+    #[test]
+    fn should_only_keep_the_commits_of_the_given_author() {
+        // Arrange
+        let mut previous = Release::fixture();
+        for commit in &mut previous.commits {
+            commit.author_username = None;
+            commit.commit.author = "James Delleck".to_string();
+        }
+        let mut release = Release::fixture();
+        release.previous = Some(Box::new(previous));
+
+        // Act
+        let release = release.retain_author("oknozor");
+
+        // Assert
+        let summaries: Vec<&str> = release
+            .commits
+            .iter()
+            .map(|commit| commit.commit.conventional.summary.as_str())
+            .collect();
+        assert_that!(summaries).is_equal_to(vec![
+            "fix parser implementation",
+            "implement the changelog generator",
+        ]);
+        let previous = release.previous.expect("release boundaries are kept");
+        assert_that!(previous.commits.is_empty()).is_true();
+    }
+
+    #[test]
+    fn should_only_render_commits_within_the_date_window() -> Result<()> {
+        // Arrange
+        let day = |day: u32| {
+            NaiveDate::from_ymd_opt(2015, 9, day)
+                .and_then(|date| date.and_hms_opt(12, 0, 0))
+                .unwrap()
+        };
+        let mut previous = Release::fixture();
+        for commit in &mut previous.commits {
+            commit.commit.date = day(1);
+        }
+        let mut release = Release::fixture();
+        for (commit, date) in release.commits.iter_mut().zip([3, 5, 7]) {
+            commit.commit.date = day(date);
+        }
+        release.previous = Some(Box::new(previous));
+
+        // Act
+        let release = release.retain_date_window(day(2), day(6));
+        let changelog = Renderer::default().render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn should_render_default_template() -> Result<()> {
         // Arrange
@@ -169,6 +898,54 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn should_render_default_template_with_footers() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        let footers = &mut release.commits[1].commit.conventional.footers;
+        for (token, content) in [
+            ("Refs", "#42"),
+            ("Co-authored-by", "Jane Doe <jane@example.org>"),
+            ("Co-authored-by", "John Doe <john@example.org>"),
+        ] {
+            footers.push(Footer {
+                token: token.to_string(),
+                content: content.to_string(),
+                ..Default::default()
+            });
+        }
+
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::DefaultWithFooters,
+        })?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                  - token: content
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                  - token: content
+                - awesome feature - (17f7e23) - Paul Delafosse
+                  - token: content
+                  - Refs: #42
+                  - Co-authored-by: Jane Doe <jane@example.org>
+                  - Co-authored-by: John Doe <john@example.org>
+                "
+            }
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn should_render_full_hash_template() -> Result<()> {
         // Arrange
@@ -231,6 +1008,105 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn should_render_gitlab_template() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::try_new(Template::from_arg(
+            "gitlab_remote",
+            RemoteContext::try_new(
+                Some("gitlab.com".into()),
+                Some("cocogitto".into()),
+                Some("cocogitto".into()),
+            ),
+        )?)?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## [1.0.0](https://gitlab.com/cocogitto/cocogitto/-/compare/0.1.0...1.0.0) - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - ([17f7e23](https://gitlab.com/cocogitto/cocogitto/-/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://gitlab.com/oknozor)
+                #### Features
+                - **(parser)** implement the changelog generator - ([17f7e23](https://gitlab.com/cocogitto/cocogitto/-/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://gitlab.com/oknozor)
+                - awesome feature - ([17f7e23](https://gitlab.com/cocogitto/cocogitto/-/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_gitea_template_on_self_hosted_instance() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::try_new(Template::from_arg(
+            "gitea_remote",
+            RemoteContext::try_new(
+                Some("git.example.org".into()),
+                Some("cocogitto".into()),
+                Some("cocogitto".into()),
+            ),
+        )?)?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## [1.0.0](https://git.example.org/cocogitto/cocogitto/compare/0.1.0..1.0.0) - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - ([17f7e23](https://git.example.org/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://git.example.org/oknozor)
+                #### Features
+                - **(parser)** implement the changelog generator - ([17f7e23](https://git.example.org/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://git.example.org/oknozor)
+                - awesome feature - ([17f7e23](https://git.example.org/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_bitbucket_template() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::try_new(Template::from_arg(
+            "bitbucket_remote",
+            RemoteContext::try_new(
+                Some("bitbucket.org".into()),
+                Some("cocogitto".into()),
+                Some("cocogitto".into()),
+            ),
+        )?)?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## [1.0.0](https://bitbucket.org/cocogitto/cocogitto/branches/compare/1.0.0..0.1.0) - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - ([17f7e23](https://bitbucket.org/cocogitto/cocogitto/commits/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://bitbucket.org/oknozor)
+                #### Features
+                - **(parser)** implement the changelog generator - ([17f7e23](https://bitbucket.org/cocogitto/cocogitto/commits/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://bitbucket.org/oknozor)
+                - awesome feature - ([17f7e23](https://bitbucket.org/cocogitto/cocogitto/commits/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn should_render_template_monorepo() -> Result<()> {
         // Arrange
@@ -266,6 +1142,71 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn should_render_compact_template_monorepo() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::MonorepoCompact,
+        })?;
+
+        let mut renderer = monorepo_renderer(renderer)?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                ### Package updates
+                - one bumped to 0.1.0
+                - two bumped to 0.2.0
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_template_monorepo_with_package_heading_level() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::MonorepoDefault,
+        })?
+        .with_package_heading_level(2);
+
+        let mut renderer = monorepo_renderer(renderer)?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                ## Package updates
+                - one bumped to 0.1.0
+                - two bumped to 0.2.0
+                ## Global changes
+                ### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                ### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn should_render_template_monorepo_for_manual_bump() -> Result<()> {
         // Arrange
@@ -393,16 +1334,152 @@ mod test {
         assert_eq!(
             changelog,
             indoc! {
-                "## [1.0.0](https://github.com/cocogitto/cocogitto/compare/0.1.0..1.0.0) - 2015-09-05
-                ### Package updates
-                - [0.1.0](crates/one) bumped to [0.1.0](https://github.com/cocogitto/cocogitto/compare/0.2.0..0.1.0)
-                - [0.2.0](crates/two) bumped to [0.2.0](https://github.com/cocogitto/cocogitto/compare/0.3.0..0.2.0)
-                ### Global changes
+                "## [1.0.0](https://github.com/cocogitto/cocogitto/compare/0.1.0..1.0.0) - 2015-09-05
+                ### Package updates
+                - [0.1.0](crates/one) bumped to [0.1.0](https://github.com/cocogitto/cocogitto/compare/0.2.0..0.1.0)
+                - [0.2.0](crates/two) bumped to [0.2.0](https://github.com/cocogitto/cocogitto/compare/0.3.0..0.2.0)
+                ### Global changes
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor)
+                #### Features
+                - **(parser)** implement the changelog generator - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor)
+                - awesome feature - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_monorepo_package_paths_with_forward_slashes() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".into()),
+                Some("cocogitto".into()),
+                Some("cocogitto".into()),
+            ),
+            kind: TemplateKind::MonorepoRemote,
+        })?;
+
+        let mut context = monorepo_context()?;
+        context.packages[0].package_path = "crates\\one";
+        context.packages[1].package_path = "crates\\nested\\two";
+        let mut renderer = renderer.with_monorepo_context(context);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.contains(
+            "- [0.1.0](crates/one) bumped to [0.1.0](https://github.com/cocogitto/cocogitto/compare/0.2.0..0.1.0)\n"
+        ));
+        assert!(changelog.contains(
+            "- [0.2.0](crates/nested/two) bumped to [0.2.0](https://github.com/cocogitto/cocogitto/compare/0.3.0..0.2.0)\n"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_group_monorepo_breaking_changes_by_package() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        for (commit, scope) in release
+            .commits
+            .iter_mut()
+            .zip([Some("two"), None, Some("one")])
+        {
+            commit.commit.conventional.is_breaking_change = true;
+            commit.commit.conventional.scope = scope.map(str::to_string);
+        }
+
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::MonorepoDefault,
+        })?
+        .with_monorepo_context(monorepo_context()?)
+        .with_breaking_change_grouping(BreakingChangeGrouping::Package);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_that!(changelog).contains(indoc! {
+            "#### ⚠ BREAKING CHANGES
+            - awesome feature
+            ##### one
+            - implement the changelog generator
+            ##### two
+            - fix parser implementation
+            #### Bug Fixes
+            "
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_template_package() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::PackageDefault,
+        })?;
+
+        let mut renderer = package_renderer(renderer)?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_template_package_with_scope_filter() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[2].commit.conventional.scope = Some("cli".to_string());
+        let renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::PackageDefault,
+        })?;
+
+        let mut renderer = renderer.with_package_context(PackageContext {
+            package_name: "one",
+            scope_filter: Some(vec!["parser".to_string()]),
+            include_unscoped: true,
+        });
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
                 #### Bug Fixes
-                - **(parser)** fix parser implementation - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor)
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
                 #### Features
-                - **(parser)** implement the changelog generator - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor)
-                - awesome feature - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - Paul Delafosse
+                - awesome feature - (17f7e23) - Paul Delafosse
                 "
             }
         );
@@ -411,7 +1488,7 @@ mod test {
     }
 
     #[test]
-    fn should_render_template_package() -> Result<()> {
+    fn should_render_template_package_with_scope_filter_without_unscoped() -> Result<()> {
         // Arrange
         let release = Release::fixture();
         let renderer = Renderer::try_new(Template {
@@ -419,7 +1496,11 @@ mod test {
             kind: TemplateKind::PackageDefault,
         })?;
 
-        let mut renderer = package_renderer(renderer)?;
+        let mut renderer = renderer.with_package_context(PackageContext {
+            package_name: "one",
+            scope_filter: Some(vec!["parser".to_string()]),
+            include_unscoped: false,
+        });
 
         // Act
         let changelog = renderer.render(release)?;
@@ -433,7 +1514,6 @@ mod test {
                 - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
                 #### Features
                 - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
-                - awesome feature - (17f7e23) - Paul Delafosse
                 "
             }
         );
@@ -572,6 +1652,11 @@ mod test {
                 commits: vec![
                     ChangelogCommit {
                         author_username: Some("oknozor"),
+                        author_email: None,
+                        author_url: None,
+                        commit_signature: None,
+                        reverted: false,
+                        branch: None,
                         commit: Commit {
                             oid: a_commit_hash.to_string(),
                             conventional: ConventionalCommit {
@@ -592,6 +1677,11 @@ mod test {
                     },
                     ChangelogCommit {
                         author_username: None,
+                        author_email: None,
+                        author_url: None,
+                        commit_signature: None,
+                        reverted: false,
+                        branch: None,
                         commit: Commit {
                             oid: a_commit_hash.to_string(),
                             conventional: ConventionalCommit {
@@ -612,6 +1702,11 @@ mod test {
                     },
                     ChangelogCommit {
                         author_username: Some("oknozor"),
+                        author_email: None,
+                        author_url: None,
+                        commit_signature: None,
+                        reverted: false,
+                        branch: None,
                         commit: Commit {
                             oid: a_commit_hash.to_string(),
                             conventional: ConventionalCommit {
@@ -632,12 +1727,21 @@ mod test {
                     },
                 ],
                 previous: None,
+                tag_message: None,
+                tag_aliases: vec![],
+                diff_stat: None,
+                timestamp: Some(date.and_utc()),
+                pre_releases: vec![],
             }
         }
     }
 
     fn monorepo_renderer(renderer: Renderer) -> Result<Renderer> {
-        let renderer = renderer.with_monorepo_context(MonoRepoContext {
+        Ok(renderer.with_monorepo_context(monorepo_context()?))
+    }
+
+    fn monorepo_context() -> Result<MonoRepoContext<'static>> {
+        Ok(MonoRepoContext {
             package_lock: false,
             packages: vec![
                 PackageBumpContext {
@@ -669,9 +1773,48 @@ mod test {
                     )?)),
                 },
             ],
-        });
+        })
+    }
 
-        Ok(renderer)
+    #[test]
+    fn package_summaries_should_match_rendered_package_lines() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let context = monorepo_context()?;
+        let summaries = context.package_summaries();
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::MonorepoDefault,
+        })?
+        .with_monorepo_context(context);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_that!(summaries).is_equal_to(vec![
+            PackageBumpSummary {
+                name: "one".to_string(),
+                path: "crates/one".to_string(),
+                from: Some("0.2.0".to_string()),
+                to: "0.1.0".to_string(),
+                locked: false,
+            },
+            PackageBumpSummary {
+                name: "two".to_string(),
+                path: "crates/two".to_string(),
+                from: Some("0.3.0".to_string()),
+                to: "0.2.0".to_string(),
+                locked: false,
+            },
+        ]);
+
+        for summary in summaries {
+            let line = format!("- {} bumped to {}\n", summary.name, summary.to);
+            assert_that!(changelog).contains(line.as_str());
+        }
+
+        Ok(())
     }
 
     fn monorepo_manual_bump_rendered(renderer: Renderer) -> Result<Renderer> {
@@ -707,8 +1850,717 @@ mod test {
     fn package_renderer(renderer: Renderer) -> Result<Renderer> {
         let renderer = renderer.with_package_context(PackageContext {
             package_name: "one",
+            scope_filter: None,
+            include_unscoped: false,
         });
 
         Ok(renderer)
     }
+
+    #[sealed_test]
+    fn should_skip_commits_matching_excluded_summary_patterns() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        let settings = Settings {
+            changelog: Changelog {
+                exclude_summary_patterns: vec![
+                    r"\[skip changelog\]".to_string(),
+                    "^WIP".to_string(),
+                ],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let settings = toml::to_string(&settings)?;
+        run_cmd!(echo $settings > cog.toml;)?;
+
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        commit("[skip changelog] feat: x")?;
+        commit("fix: bug fix")?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("..")?)?;
+
+        // Assert
+        let summaries: Vec<&str> = release
+            .commits
+            .iter()
+            .map(|commit| commit.commit.conventional.summary.as_str())
+            .collect();
+
+        assert_that!(summaries).is_equal_to(vec!["bug fix", "feature 1", "first commit"]);
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_skip_merge_commits_and_keep_their_release_boundary() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write("cog.toml", "[changelog]\nskip_merge_commits = true\n")?;
+        commit("chore: first commit")?;
+        run_cmd!(git checkout -q -b branch;)?;
+        commit("feat: feature 1")?;
+        run_cmd!(
+            git checkout -q master;
+            git merge -q --no-ff -m "chore: merge branch" branch;
+        )?;
+        git_tag("1.0.0")?;
+        commit("fix: bug fix")?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("..")?)?;
+
+        // Assert
+        let summaries = |release: &Release| -> Vec<String> {
+            release
+                .commits
+                .iter()
+                .map(|commit| commit.commit.conventional.summary.clone())
+                .collect()
+        };
+
+        assert_that!(summaries(&release)).is_equal_to(vec!["bug fix".to_string()]);
+        let previous = release.previous.expect("a tagged release");
+        assert_that!(previous.version.to_string()).is_equal_to("1.0.0".to_string());
+        assert_that!(summaries(&previous)).is_equal_to(vec!["feature 1".to_string()]);
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_stream_the_releases_from_the_latest_to_the_oldest() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        git_tag("0.1.0")?;
+        commit("feat: feature 2")?;
+        git_tag("0.2.0")?;
+
+        // Act
+        let versions = Release::releases_iter(repo.revwalk("..")?)
+            .map(|release| release.map(|release| release.version.to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Assert
+        assert_that!(versions).is_equal_to(vec!["0.2.0".to_string(), "0.1.0".to_string()]);
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_stream_the_releases_of_the_chain() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        git_tag("0.1.0")?;
+        commit("fix: bug fix")?;
+        commit("feat: feature 2")?;
+        git_tag("0.2.0")?;
+        commit("feat: unreleased feature")?;
+        let now = Utc::now();
+        let describe = |release: &Release| -> (String, String, Vec<String>) {
+            (
+                release.version.to_string(),
+                release.from.to_string(),
+                release
+                    .commits
+                    .iter()
+                    .map(|commit| commit.commit.conventional.summary.clone())
+                    .collect(),
+            )
+        };
+
+        // Act
+        let cache = CommitCache::default();
+        let streamed = Release::cached_releases_iter(repo.revwalk("..")?, now, &cache)
+            .map(|release| release.map(|release| describe(&release)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let chain = Release::from_commits(repo.revwalk("..")?, now, &cache)?;
+
+        // Assert
+        let mut expected = vec![];
+        let mut current = Some(&chain);
+        while let Some(release) = current {
+            expected.push(describe(release));
+            current = release.previous.as_deref();
+        }
+
+        assert_that!(streamed).has_length(3);
+        assert_that!(streamed).is_equal_to(expected);
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_skip_octopus_merge_commits() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write("cog.toml", "[changelog]\nskip_merge_commits = true\n")?;
+        commit("chore: first commit")?;
+        run_cmd!(git checkout -q -b one;)?;
+        commit("feat: feature 1")?;
+        run_cmd!(git checkout -q master; git checkout -q -b two;)?;
+        commit("feat: feature 2")?;
+        run_cmd!(
+            git checkout -q master;
+            git merge -q --no-ff -m "chore: merge branches" one two;
+        )?;
+        git_tag("1.0.0")?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("..")?)?;
+
+        // Assert
+        let mut summaries: Vec<String> = release
+            .commits
+            .iter()
+            .map(|commit| commit.commit.conventional.summary.clone())
+            .collect();
+        summaries.sort();
+
+        assert_that!(release.version.to_string()).is_equal_to("1.0.0".to_string());
+        assert_that!(summaries).is_equal_to(vec!["feature 1".to_string(), "feature 2".to_string()]);
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_render_a_single_unreleased_section_without_tags() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        let first = commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        let head = commit("fix: bug fix")?;
+        let (first, head) = (&first[..7], &head[..7]);
+
+        let remote = Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".into()),
+                Some("cocogitto".into()),
+                Some("cocogitto".into()),
+            ),
+            kind: TemplateKind::Remote,
+        };
+
+        // Act
+        let changelog =
+            Release::try_from(repo.revwalk("..")?)?.into_markdown(Template::default())?;
+        let remote_changelog = Release::try_from(repo.revwalk("..")?)?.into_markdown(remote)?;
+
+        // Assert
+        let headers: Vec<&str> = changelog
+            .lines()
+            .filter(|line| line.starts_with("## "))
+            .collect();
+        let header = format!("## Unreleased ({first}..{head})");
+        assert_that!(headers).is_equal_to(vec![header.as_str()]);
+        assert_that!(changelog
+            .lines()
+            .filter(|line| line.starts_with("- "))
+            .count())
+        .is_equal_to(3);
+        assert!(remote_changelog.starts_with(&format!(
+            "## Unreleased ([{first}..{head}](https://github.com/cocogitto/cocogitto/compare/{first}..HEAD))\n"
+        )));
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_attach_merged_branch_name_to_its_commits() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write("cog.toml", "[changelog]\nbranch_origin = true\n")?;
+        commit("chore: first commit")?;
+        run_cmd!(git checkout -q -b feature/parser;)?;
+        commit("feat: feature 1")?;
+        commit("feat: feature 2")?;
+        run_cmd!(git checkout -q master;)?;
+        commit("fix: bug fix")?;
+        run_cmd!(git merge -q --no-ff -m "Merge branch 'feature/parser'" feature/parser;)?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("..")?)?;
+
+        // Assert
+        let branches: Vec<(String, Option<String>)> = release
+            .commits
+            .iter()
+            .map(|commit| {
+                (
+                    commit.commit.conventional.summary.clone(),
+                    commit.branch.clone(),
+                )
+            })
+            .collect();
+
+        assert_that!(branches).is_equal_to(vec![
+            ("bug fix".to_string(), None),
+            ("feature 2".to_string(), Some("feature/parser".to_string())),
+            ("feature 1".to_string(), Some("feature/parser".to_string())),
+        ]);
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_render_placeholder_for_releases_with_only_omitted_commits() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write("cog.toml", "[commit_types]\nchore = { changelog_title = \"Chores\", omit_from_changelog = true }\n")?;
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        git_tag("1.0.0")?;
+        commit("chore: bump dependencies")?;
+        git_tag("1.0.1")?;
+        commit("chore: format code")?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("..")?)?;
+        let changelog = release.into_markdown(Template::default())?;
+
+        // Assert
+        let lines: Vec<&str> = changelog.lines().collect();
+        assert!(lines[0].starts_with("## Unreleased ("));
+        assert_eq!(lines[1], "- _no notable changes_");
+        assert!(lines[5].starts_with("## 1.0.1 - "));
+        assert_eq!(lines[6], "- _no notable changes_");
+        assert!(lines[10].starts_with("## 1.0.0 - "));
+        assert_eq!(lines[11], "#### Features");
+        assert_eq!(changelog.matches("_no notable changes_").count(), 2);
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_render_custom_placeholder_for_releases_with_only_omitted_commits() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write("cog.toml", "[commit_types]\nchore = { changelog_title = \"Chores\", omit_from_changelog = true }\n[changelog]\nempty_release_placeholder = \"Maintenance release.\"\n")?;
+        commit("feat: feature 1")?;
+        git_tag("1.0.0")?;
+        commit("chore: bump dependencies")?;
+        commit("chore: format code")?;
+        git_tag("1.0.1")?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("1.0.0..1.0.1")?)?;
+        let changelog = release.into_markdown(Template::default())?;
+
+        // Assert
+        assert!(changelog.ends_with("\nMaintenance release.\n"));
+        assert!(!changelog.contains("####"));
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_only_render_the_commits_of_the_author_allowlist() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write(
+            "cog.toml",
+            indoc! {
+                r#"
+                [changelog]
+                author_allowlist = ["jane"]
+                authors = [{ signature = "Jane Doe", username = "jane" }]
+                "#
+            },
+        )?;
+        commit("chore: first commit")?;
+        commit("feat: other team feature")?;
+        git_tag("1.0.0")?;
+        commit("feat: team feature\n\nCo-authored-by: Jane Doe <jane@example.com>")?;
+        commit("fix: other team fix")?;
+        git_tag("1.1.0")?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("..")?)?;
+        let changelog = release.into_markdown(Template::default())?;
+
+        // Assert
+        assert_that!(changelog).contains("- team feature");
+        assert_that!(changelog).does_not_contain("other team");
+        assert_that!(changelog).does_not_contain("## 1.0.0");
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_render_the_commit_labels_setting_as_badges() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write(
+            "cog.toml",
+            indoc! {
+                r#"
+                [changelog.commit_labels]
+                "42" = ["enhancement", "parser"]
+                "#
+            },
+        )?;
+        commit("chore: first commit")?;
+        commit("feat: labeled feature (#42)")?;
+        commit("fix: unlabeled fix (#43)")?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("..")?)?;
+        let changelog = release.into_markdown(Template::default())?;
+
+        // Assert
+        assert_that!(changelog).contains("- labeled feature (#42) - ");
+        assert_that!(changelog).contains(" `enhancement` `parser`\n");
+        assert_that!(changelog.matches('`').count()).is_equal_to(4);
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_render_sections_from_the_section_mapping_file() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write(
+            "cog.toml",
+            "[changelog]\nsection_mapping = \"sections.toml\"\n",
+        )?;
+        fs::write(
+            "sections.toml",
+            indoc! {
+                r#"
+                [[sections]]
+                type = "fix"
+                title = "Fixes"
+                emoji = "🐛"
+
+                [[sections]]
+                type = "feat"
+                emoji = "✨"
+
+                [[sections]]
+                type = "chore"
+                omit = true
+                "#
+            },
+        )?;
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        commit("fix: bug fix")?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("..")?)?;
+        let changelog = release.into_markdown(Template::default())?;
+
+        // Assert
+        let fixes = changelog.find("#### 🐛 Fixes\n- bug fix - ");
+        let features = changelog.find("#### ✨ Features\n- feature 1 - ");
+        assert!(fixes.is_some() && features.is_some());
+        assert!(fixes < features);
+        assert!(!changelog.contains("first commit"));
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_strike_through_features_reverted_in_the_same_release() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write(
+            "cog.toml",
+            "[changelog]\nrevert_handling = \"strike_through\"\n",
+        )?;
+        commit("chore: first commit")?;
+        let feature = commit("feat: awesome feature")?;
+        commit("feat: other feature")?;
+        commit(&format!(
+            "revert: awesome feature\n\nThis reverts commit {feature}."
+        ))?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("..")?)?;
+        let changelog = release.into_markdown(Template::default())?;
+
+        // Assert
+        assert!(changelog.contains("- ~~awesome feature~~ - "));
+        assert!(changelog.contains("- other feature - "));
+        assert!(!changelog.contains("#### Revert"));
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_drop_features_reverted_in_the_same_release() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write("cog.toml", "[changelog]\nrevert_handling = \"drop\"\n")?;
+        commit("chore: first commit")?;
+        let feature = commit("feat: awesome feature")?;
+        commit("feat: other feature")?;
+        commit(&format!(
+            "revert: awesome feature\n\nThis reverts commit {}.",
+            &feature[..7]
+        ))?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("..")?)?;
+
+        // Assert
+        let summaries: Vec<&str> = release
+            .commits
+            .iter()
+            .map(|commit| commit.commit.conventional.summary.as_str())
+            .collect();
+
+        assert_that!(summaries).is_equal_to(vec!["other feature", "first commit"]);
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_skip_commits_with_changelog_skip_footer() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        commit("feat: internal feature\n\nChangelog: skip")?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("..")?)?;
+
+        // Assert
+        let summaries: Vec<&str> = release
+            .commits
+            .iter()
+            .map(|commit| commit.commit.conventional.summary.as_str())
+            .collect();
+
+        assert_that!(summaries).is_equal_to(vec!["feature 1", "first commit"]);
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_render_non_conventional_commits_under_other_changes() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        let settings = Settings {
+            changelog: Changelog {
+                include_non_conventional: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let settings = toml::to_string(&settings)?;
+        run_cmd!(echo $settings > cog.toml;)?;
+
+        commit("chore: first commit")?;
+        commit("random commit message")?;
+        let release = Release::try_from(repo.revwalk("..")?)?;
+
+        // Act
+        let changelog = Renderer::default().render(release)?;
+
+        // Assert
+        assert_that!(changelog).contains("#### Other Changes\n- random commit message - (");
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_skip_non_conventional_commits_by_default() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        commit("chore: first commit")?;
+        commit("random commit message")?;
+        let release = Release::try_from(repo.revwalk("..")?)?;
+
+        // Act
+        let changelog = Renderer::default().render(release)?;
+
+        // Assert
+        assert_that!(changelog).does_not_contain("random commit message");
+        assert_that!(changelog).does_not_contain("Other Changes");
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_override_summary_with_changelog_footer() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        commit("chore: first commit")?;
+        commit("feat: add flag\n\nChangelog: support the --dry-run flag on every command")?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("..")?)?;
+
+        // Assert
+        let summaries: Vec<&str> = release
+            .commits
+            .iter()
+            .map(|commit| commit.commit.conventional.summary.as_str())
+            .collect();
+
+        assert_that!(summaries).is_equal_to(vec![
+            "support the --dry-run flag on every command",
+            "first commit",
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn should_merge_releases_with_repository_labels() -> Result<()> {
+        // Arrange
+        let api = Release::fixture();
+        let mut web = Release::fixture();
+        web.commits.truncate(2);
+
+        // Act
+        let merged = merge_releases(vec![("api".to_string(), api), ("web".to_string(), web)])?;
+
+        // Assert
+        let entries: Vec<(Option<&str>, &str, Option<&str>)> = merged
+            .commits
+            .iter()
+            .map(|commit| {
+                (
+                    commit.commit.conventional.scope.as_deref(),
+                    commit.commit.conventional.summary.as_str(),
+                    commit.author_username,
+                )
+            })
+            .collect();
+
+        assert_that!(entries).is_equal_to(vec![
+            (
+                Some("api/parser"),
+                "fix parser implementation",
+                Some("oknozor"),
+            ),
+            (Some("api"), "awesome feature", None),
+            (
+                Some("api/parser"),
+                "implement the changelog generator",
+                Some("oknozor"),
+            ),
+            (
+                Some("web/parser"),
+                "fix parser implementation",
+                Some("oknozor"),
+            ),
+            (Some("web"), "awesome feature", None),
+        ]);
+        assert_that!(merged.previous.is_none()).is_true();
+
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_render_commits_sharing_a_timestamp_in_a_stable_order() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        std::env::set_var("GIT_COMMITTER_DATE", "2015-09-05T12:00:00+00:00");
+        commit("chore: first commit")?;
+        // Unrelated branches, only their oids can order their commits
+        let mut oids = vec![];
+        for branch in ["first", "second", "third", "fourth"] {
+            run_cmd!(git checkout -q -b $branch master;)?;
+            oids.push(commit(&format!("feat: {branch}"))?);
+        }
+        run_cmd!(
+            git checkout -q master;
+            git merge -q --no-ff -m "chore: merge features" first second third fourth;
+        )?;
+        let now = DateTime::from_timestamp(1441454400, 0).unwrap();
+        let render = || -> Result<String> {
+            let release = Release::from_commits(repo.revwalk("..")?, now, &CommitCache::default())?;
+            Ok(Renderer::default().render(release)?)
+        };
+
+        // Act
+        let changelog = render()?;
+
+        // Assert
+        assert_that!(render()?).is_equal_to(&changelog);
+        oids.sort();
+        let features = &changelog[changelog.find("#### Features").unwrap()..];
+        let shorthands: Vec<usize> = oids
+            .iter()
+            .map(|oid| features.find(&oid[..7]).unwrap())
+            .collect();
+        assert_that!(shorthands.windows(2).all(|pair| pair[0] < pair[1])).is_true();
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_render_release_diff_stat() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        commit("chore: first commit")?;
+        fs::write("file", "one\ntwo\nthree\n")?;
+        run_cmd!(git add .;)?;
+        commit("feat: add file")?;
+        fs::write("file", "one\n2\n")?;
+        fs::write("other", "other\n")?;
+        run_cmd!(git add .;)?;
+        commit("fix: change file")?;
+        let release = Release::try_from(repo.revwalk("..")?)?.with_diff_stats(&repo)?;
+        let mut renderer = Renderer::default();
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_that!(changelog).contains("2 files changed, +5/-2 across 3 commits\n");
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_merge_sections_sharing_a_title() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write(
+            "cog.toml",
+            indoc! {
+                r#"
+                [commit_types]
+                fix = { changelog_title = "Improvements" }
+                perf = { changelog_title = "Improvements" }
+                "#
+            },
+        )?;
+        commit("chore: first commit")?;
+        commit("fix: bug fix")?;
+        commit("perf: speed up")?;
+        let release = Release::try_from(repo.revwalk("..")?)?;
+        let mut renderer = Renderer::default();
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_that!(changelog.matches("#### Improvements").count()).is_equal_to(1);
+        let perf = changelog.find("speed up").unwrap();
+        let fix = changelog.find("bug fix").unwrap();
+        assert_that!(perf < fix).is_true();
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_split_sections_sharing_a_title_when_merge_is_disabled() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write(
+            "cog.toml",
+            indoc! {
+                r#"
+                [commit_types]
+                fix = { changelog_title = "Improvements" }
+                perf = { changelog_title = "Improvements" }
+                "#
+            },
+        )?;
+        commit("chore: first commit")?;
+        commit("fix: bug fix")?;
+        commit("perf: speed up")?;
+        let release = Release::try_from(repo.revwalk("..")?)?;
+        let mut renderer = Renderer::default().with_merge_duplicate_sections(false);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_that!(changelog).contains("#### Improvements (fix)\n- bug fix");
+        assert_that!(changelog).contains("#### Improvements (perf)\n- speed up");
+        Ok(())
+    }
 }
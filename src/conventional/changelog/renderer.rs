@@ -1,17 +1,120 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
+use chrono::format::{Item, StrftimeItems};
+use chrono::NaiveDateTime;
+use chrono_tz::Tz;
+use colored::{ColoredString, Colorize};
+use conventional_commit_parser::commit::CommitType;
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use serde::Serialize;
 use tera::{dotted_pointer, to_value, try_get_value, Context, Tera, Value};
 
-use crate::conventional::changelog::release::Release;
+use crate::conventional::changelog::error::ChangelogError;
+use crate::conventional::changelog::release::{ChangelogCommit, ChangelogFooter, Release};
+use crate::conventional::changelog::serde::changelog_title;
 use crate::conventional::changelog::template::{
-    MonoRepoContext, PackageContext, RemoteContext, Template, ToContext,
+    MonoRepoContext, PackageContext, RemoteContext, Template, TemplateKind, ToContext,
 };
+use crate::git::oid::OidOf;
+use crate::settings::{
+    AuthorLinkFallback, AuthorStyle, BreakingChangeGrouping, FooterStyle, GroupBy, GroupFallback,
+    Grouping, HashPosition, HeaderStyle, HeadingSyntax, SameCommitTags, ScopeFormat, SummaryStyle,
+    TypeOrder,
+};
+use crate::COMMITS_METADATA;
+
+// Section of the commits rendered without heading, see `GroupFallback::Inline`
+const INLINE_SECTION: &str = "__inline__";
+// Footer marking a commit as a release highlight, `true` or the text to highlight
+const HIGHLIGHT_TOKEN: &str = "Highlight";
+static PULL_REQUEST: Lazy<Regex> = Lazy::new(|| Regex::new(r"#(\d+)\b").expect("valid regex"));
+const OTHERS_SECTION: &str = "Others";
 
 #[derive(Debug)]
 pub struct Renderer {
     tera: Tera,
     context: Context,
     template: Template,
+    commit_url_template: Option<String>,
+    deployment_url_template: Option<String>,
+    date_format: String,
+    release_date_format: String,
+    type_order: TypeOrder,
+    section_order: Vec<String>,
+    aggregated_footers: Vec<String>,
+    header_style: HeaderStyle,
+    heading_syntax: HeadingSyntax,
+    milestone_token: Option<String>,
+    commit_line_suffix: Option<String>,
+    author_style: Option<AuthorStyle>,
+    type_abbreviations: HashMap<CommitType, String>,
+    scope_format: ScopeFormat,
+    hash_position: Option<HashPosition>,
+    footer_style: FooterStyle,
+    summary_style: SummaryStyle,
+    scoped_commits_first: bool,
+    trusted_keys: Option<HashSet<String>>,
+    commit_labels: HashMap<String, Vec<String>>,
+    summary_translations: HashMap<String, String>,
+    unreleased_compare_ref: String,
+    unreleased_label: String,
+    tag_message: bool,
+    issue_trackers: HashMap<String, String>,
+    issue_reference: Option<Regex>,
+    grouping: Grouping,
+    same_commit_tags: SameCommitTags,
+    thousands_separator: Option<String>,
+    compare_links: bool,
+    dedup_releases: bool,
+    fold_pre_releases: bool,
+    required_label: Option<(String, String)>,
+    scope_filter: Option<Vec<String>>,
+    include_unscoped: bool,
+    contributor_counts: bool,
+    toc_max_level: Option<usize>,
+    wrap_width: Option<usize>,
+    package_heading_level: usize,
+    timezone: Option<Tz>,
+    merge_duplicate_sections: bool,
+    initial_version_label: Option<String>,
+    baseline: Option<String>,
+    author_allowlist: Option<HashSet<String>>,
+    scope_counts: bool,
+    strip_tag_prefixes: bool,
+    author_link_fallback: AuthorLinkFallback,
+    breaking_change_scopes: bool,
+    breaking_change_grouping: BreakingChangeGrouping,
+    always_show_types: Vec<CommitType>,
+    internal_types: Vec<CommitType>,
+    empty_section_placeholder: Option<String>,
+    empty_release_placeholder: Option<String>,
+    activity_bar: bool,
+    busiest_release: usize,
+}
+
+#[derive(Serialize)]
+struct FooterSection<'a> {
+    token: &'a str,
+    footers: Vec<ChangelogFooter<'a>>,
+}
+
+#[derive(Serialize)]
+struct ScopeGroup<'a> {
+    scope: &'a str,
+    sections: Vec<ScopeSection<'a>>,
+}
+
+#[derive(Serialize)]
+struct BreakingGroup<'a> {
+    package: Option<&'a str>,
+    commits: Vec<&'a Value>,
+}
+
+#[derive(Serialize)]
+struct ScopeSection<'a> {
+    title: &'a str,
+    commits: Vec<&'a Value>,
 }
 
 impl Default for Renderer {
@@ -29,16 +132,447 @@ impl Renderer {
         tera.add_raw_template(template.kind.name(), content.as_ref())?;
         tera.register_filter("upper_first", Self::upper_first_filter);
         tera.register_filter("unscoped", Self::unscoped);
+        tera.register_filter("format_count", Self::format_count);
 
         Ok(Renderer {
             tera,
             context: Context::new(),
             template,
+            commit_url_template: None,
+            deployment_url_template: None,
+            date_format: "%Y-%m-%d".to_string(),
+            release_date_format: "%Y-%m-%d".to_string(),
+            type_order: TypeOrder::default(),
+            section_order: vec![],
+            aggregated_footers: vec![],
+            header_style: HeaderStyle::default(),
+            heading_syntax: HeadingSyntax::default(),
+            milestone_token: None,
+            commit_line_suffix: None,
+            author_style: None,
+            type_abbreviations: HashMap::new(),
+            scope_format: ScopeFormat::default(),
+            hash_position: None,
+            footer_style: FooterStyle::default(),
+            summary_style: SummaryStyle::default(),
+            scoped_commits_first: false,
+            trusted_keys: None,
+            commit_labels: HashMap::new(),
+            summary_translations: HashMap::new(),
+            unreleased_compare_ref: "HEAD".to_string(),
+            unreleased_label: "Unreleased".to_string(),
+            tag_message: false,
+            issue_trackers: HashMap::new(),
+            issue_reference: None,
+            grouping: Grouping::default(),
+            same_commit_tags: SameCommitTags::default(),
+            thousands_separator: None,
+            compare_links: false,
+            dedup_releases: false,
+            fold_pre_releases: false,
+            required_label: None,
+            scope_filter: None,
+            include_unscoped: false,
+            contributor_counts: false,
+            toc_max_level: None,
+            wrap_width: None,
+            package_heading_level: 3,
+            merge_duplicate_sections: true,
+            initial_version_label: None,
+            baseline: None,
+            author_allowlist: None,
+            scope_counts: false,
+            strip_tag_prefixes: false,
+            author_link_fallback: AuthorLinkFallback::default(),
+            breaking_change_scopes: false,
+            breaking_change_grouping: BreakingChangeGrouping::default(),
+            always_show_types: vec![],
+            internal_types: vec![],
+            empty_section_placeholder: None,
+            empty_release_placeholder: None,
+            activity_bar: false,
+            busiest_release: 0,
+            timezone: None,
         })
     }
 
+    /// Collect the footers with the given tokens across the release commits
+    /// into a single deduplicated section per token.
+    pub(crate) fn with_aggregated_footers(mut self, tokens: Vec<String>) -> Self {
+        self.aggregated_footers = tokens;
+        self
+    }
+
+    /// Only render the commits whose `token` footer lists `label` in its comma separated values.
+    pub(crate) fn with_required_label(mut self, token: String, label: String) -> Self {
+        self.required_label = Some((token, label));
+        self
+    }
+
+    /// Prepend a table of contents listing the headings up to `max_level`
+    /// (`2` for version headers only, `4` to include the sections).
+    pub(crate) fn with_table_of_contents(mut self, max_level: usize) -> Self {
+        self.toc_max_level = Some(max_level);
+        self
+    }
+
+    /// Hard wrap the commit lines longer than `wrap_width` characters, continuation lines are
+    /// indented under the bullet text. Links and code spans are never split.
+    pub(crate) fn with_wrap_width(mut self, wrap_width: Option<usize>) -> Self {
+        self.wrap_width = wrap_width;
+        self
+    }
+
+    /// Only render the commits authored or co-authored by one of the given usernames,
+    /// skipping the releases left empty.
+    pub(crate) fn with_author_allowlist(mut self, usernames: HashSet<String>) -> Self {
+        self.author_allowlist = Some(usernames);
+        self
+    }
+
+    /// Append the number of commits to the scope headings when grouping by scope (`parser (3)`).
+    pub(crate) fn with_scope_counts(mut self, scope_counts: bool) -> Self {
+        self.scope_counts = scope_counts;
+        self
+    }
+
+    /// Attribute the aggregated breaking change footers to the scope of their commit,
+    /// or to the bumped package named by that scope in a monorepo.
+    pub(crate) fn with_breaking_change_scopes(mut self, breaking_change_scopes: bool) -> Self {
+        self.breaking_change_scopes = breaking_change_scopes;
+        self
+    }
+
+    /// Compare the oldest rendered release against `baseline` instead of the start of its range.
+    pub(crate) fn with_baseline(mut self, baseline: Option<String>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Group the breaking changes section of the monorepo templates by the package named
+    /// by the commit scope.
+    pub(crate) fn with_breaking_change_grouping(
+        mut self,
+        breaking_change_grouping: BreakingChangeGrouping,
+    ) -> Self {
+        self.breaking_change_grouping = breaking_change_grouping;
+        self
+    }
+
+    /// Render a bar next to each version header, proportional to the number of commits
+    /// of the release relative to the largest release of the chain (`▁` to `█`).
+    pub(crate) fn with_activity_bar(mut self, activity_bar: bool) -> Self {
+        self.activity_bar = activity_bar;
+        self
+    }
+
+    /// Render the sections of the given commit types even when a release has no such commit.
+    /// Only applies when grouping commits by type.
+    pub(crate) fn with_always_show_types(mut self, always_show_types: Vec<CommitType>) -> Self {
+        self.always_show_types = always_show_types;
+        self
+    }
+
+    /// Move the sections of the given commit types to a collapsed `Internal changes` block
+    /// after the other sections. Only applies when grouping commits by type.
+    pub(crate) fn with_internal_types(mut self, internal_types: Vec<CommitType>) -> Self {
+        self.internal_types = internal_types;
+        self
+    }
+
+    /// Line rendered under the sections without any commit, see [`Self::with_always_show_types`].
+    pub(crate) fn with_empty_section_placeholder(
+        mut self,
+        empty_section_placeholder: Option<String>,
+    ) -> Self {
+        self.empty_section_placeholder = empty_section_placeholder;
+        self
+    }
+
+    /// Line rendered in place of the body of the releases left without any commit.
+    pub(crate) fn with_empty_release_placeholder(
+        mut self,
+        empty_release_placeholder: Option<String>,
+    ) -> Self {
+        self.empty_release_placeholder = empty_release_placeholder;
+        self
+    }
+
+    /// Set the version header prefix, section headings are left untouched.
+    pub(crate) fn with_header_style(mut self, header_style: HeaderStyle) -> Self {
+        self.header_style = header_style;
+        self
+    }
+
+    /// Use Setext headings for the level 1 and 2 headings, deeper levels stay ATX.
+    pub(crate) fn with_heading_syntax(mut self, heading_syntax: HeadingSyntax) -> Self {
+        self.heading_syntax = heading_syntax;
+        self
+    }
+
+    /// Override how the template displays commit authors.
+    pub(crate) fn with_author_style(mut self, author_style: AuthorStyle) -> Self {
+        self.author_style = Some(author_style);
+        self
+    }
+
+    /// Short codes used by the flat template to prefix each commit with its type.
+    /// Types without a code fall back to the first letter of their changelog title.
+    pub(crate) fn with_type_abbreviations(
+        mut self,
+        type_abbreviations: HashMap<CommitType, String>,
+    ) -> Self {
+        self.type_abbreviations = type_abbreviations;
+        self
+    }
+
+    /// Link issue references in commit summaries and footers, picking the tracker url
+    /// template by reference prefix (`#`, `JIRA-`...). `{id}` is replaced with the issue number.
+    pub(crate) fn with_issue_trackers(mut self, issue_trackers: HashMap<String, String>) -> Self {
+        let mut prefixes: Vec<&String> = issue_trackers.keys().collect();
+        // Longest prefix first so overlapping prefixes (`GH-`, `H-`) match the most specific one
+        prefixes.sort_by_key(|prefix| std::cmp::Reverse(prefix.len()));
+        let prefixes: Vec<String> = prefixes.into_iter().map(|p| regex::escape(p)).collect();
+
+        self.issue_reference = if prefixes.is_empty() {
+            None
+        } else {
+            Regex::new(&format!(r"(^|[^\w\[/])({})(\d+)\b", prefixes.join("|"))).ok()
+        };
+        self.issue_trackers = issue_trackers;
+        self
+    }
+
+    /// Set whether the commit scope is rendered before or after the commit summary.
+    pub(crate) fn with_scope_format(mut self, scope_format: ScopeFormat) -> Self {
+        self.scope_format = scope_format;
+        self
+    }
+
+    /// Render the short commit hash before or after the entry, or not at all,
+    /// instead of the template's own placement.
+    pub(crate) fn with_hash_position(mut self, hash_position: HashPosition) -> Self {
+        self.hash_position = Some(hash_position);
+        self
+    }
+
+    /// Set how the templates listing the footers of each commit lay them out.
+    pub(crate) fn with_footer_style(mut self, footer_style: FooterStyle) -> Self {
+        self.footer_style = footer_style;
+        self
+    }
+
+    /// Badge commits signed with one of the given key fingerprints as verified,
+    /// other signed commits are only marked as signed.
+    pub(crate) fn with_trusted_keys(mut self, trusted_keys: HashSet<String>) -> Self {
+        self.trusted_keys = Some(trusted_keys);
+        self
+    }
+
+    /// Badge commits with labels fetched by the caller (pull request labels...), keyed by
+    /// commit oid or by pull request number (`42` for a `#42` reference in the summary).
+    pub(crate) fn with_commit_labels(
+        mut self,
+        commit_labels: HashMap<String, Vec<String>>,
+    ) -> Self {
+        self.commit_labels = commit_labels;
+        self
+    }
+
+    /// Replace the commit summaries with translations provided by the caller, keyed by
+    /// commit oid (full or abbreviated to 7 characters) or by original summary.
+    pub(crate) fn with_summary_translations(
+        mut self,
+        summary_translations: HashMap<String, String>,
+    ) -> Self {
+        self.summary_translations = summary_translations;
+        self
+    }
+
+    /// Link the commit hashes of the non-remote templates to `commit_url_template`,
+    /// `{sha}` being replaced with the full commit hash.
+    pub(crate) fn with_commit_url_template(mut self, commit_url_template: Option<String>) -> Self {
+        self.commit_url_template = commit_url_template;
+        self
+    }
+
+    /// Set how the commit type sections are sorted, see [`TypeOrder`].
+    pub(crate) fn with_type_order(mut self, type_order: TypeOrder) -> Self {
+        self.type_order = type_order;
+        self
+    }
+
+    /// Group the commits of each release by type, scope or author, see [`Grouping`].
+    pub(crate) fn with_grouping(mut self, grouping: Grouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Digit grouping separator of the counts rendered with the `format_count` filter.
+    pub(crate) fn with_thousands_separator(mut self, thousands_separator: Option<String>) -> Self {
+        self.thousands_separator = thousands_separator;
+        self
+    }
+
+    /// Render a single release per version when the chain lists the same version twice.
+    pub(crate) fn with_dedup_releases(mut self, dedup_releases: bool) -> Self {
+        self.dedup_releases = dedup_releases;
+        self
+    }
+
+    /// Fold the pre-releases of the chain into the next stable release.
+    pub(crate) fn with_fold_pre_releases(mut self, fold_pre_releases: bool) -> Self {
+        self.fold_pre_releases = fold_pre_releases;
+        self
+    }
+
+    /// Section titles rendered first, in this order, before the sections sorted by type order.
+    pub(crate) fn with_section_order(mut self, section_order: Vec<String>) -> Self {
+        self.section_order = section_order;
+        self
+    }
+
+    /// Set how the commit summaries are normalized, see [`SummaryStyle`].
+    pub(crate) fn with_summary_style(mut self, summary_style: SummaryStyle) -> Self {
+        self.summary_style = summary_style;
+        self
+    }
+
+    /// Render the release dates in `timezone` from their commit date.
+    pub(crate) fn with_timezone(mut self, timezone: Option<Tz>) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Replace the tail of the `default` template commit lines, see [`Self::line_suffix`].
+    pub(crate) fn with_commit_line_suffix(mut self, commit_line_suffix: Option<String>) -> Self {
+        self.commit_line_suffix = commit_line_suffix;
+        self
+    }
+
+    /// Chrono format of the release header dates, checked when rendering.
+    pub(crate) fn with_release_date_format(mut self, release_date_format: String) -> Self {
+        self.release_date_format = release_date_format;
+        self
+    }
+
+    /// Render the package headings of the monorepo templates at `package_heading_level`,
+    /// their sections one level below.
+    pub(crate) fn with_package_heading_level(mut self, package_heading_level: usize) -> Self {
+        self.package_heading_level = package_heading_level;
+        self
+    }
+
+    /// Point the compare link of the unreleased changes at `unreleased_compare_ref` instead of `HEAD`.
+    pub(crate) fn with_unreleased_compare_ref(mut self, unreleased_compare_ref: String) -> Self {
+        self.unreleased_compare_ref = unreleased_compare_ref;
+        self
+    }
+
+    /// Link the version headers to the deployment url built from `deployment_url_template`,
+    /// `{version}` being replaced with the release version.
+    pub(crate) fn with_deployment_url_template(
+        mut self,
+        deployment_url_template: Option<String>,
+    ) -> Self {
+        self.deployment_url_template = deployment_url_template;
+        self
+    }
+
+    /// Merge the tags pointing at the release commit into its version header (`Merge`)
+    /// or only render the greatest one (`Skip`).
+    pub(crate) fn with_same_commit_tags(mut self, same_commit_tags: SameCommitTags) -> Self {
+        self.same_commit_tags = same_commit_tags;
+        self
+    }
+
+    /// Append the number of commits of each contributor to the contributors section.
+    pub(crate) fn with_contributor_counts(mut self, contributor_counts: bool) -> Self {
+        self.contributor_counts = contributor_counts;
+        self
+    }
+
+    /// Render the commit types sharing a changelog title in a single section, otherwise
+    /// each one gets its own section suffixed with the type.
+    pub(crate) fn with_merge_duplicate_sections(mut self, merge_duplicate_sections: bool) -> Self {
+        self.merge_duplicate_sections = merge_duplicate_sections;
+        self
+    }
+
+    /// Name the start of the first release range `initial_version_label` instead of
+    /// its first commit.
+    pub(crate) fn with_initial_version_label(
+        mut self,
+        initial_version_label: Option<String>,
+    ) -> Self {
+        self.initial_version_label = initial_version_label;
+        self
+    }
+
+    /// Display the versions without the configured tag and package prefixes.
+    pub(crate) fn with_strip_tag_prefixes(mut self, strip_tag_prefixes: bool) -> Self {
+        self.strip_tag_prefixes = strip_tag_prefixes;
+        self
+    }
+
+    /// Link the authors without a known username following `author_link_fallback`.
+    pub(crate) fn with_author_link_fallback(
+        mut self,
+        author_link_fallback: AuthorLinkFallback,
+    ) -> Self {
+        self.author_link_fallback = author_link_fallback;
+        self
+    }
+
+    /// Format the commit dates with `date_format`, in the `strftime` syntax.
+    pub(crate) fn with_date_format(mut self, date_format: String) -> Self {
+        self.date_format = date_format;
+        self
+    }
+
+    /// Link the values of the `milestone_token` footers to the remote milestones.
+    pub(crate) fn with_milestone_token(mut self, milestone_token: Option<String>) -> Self {
+        self.milestone_token = milestone_token;
+        self
+    }
+
+    /// List the scoped commits of each section before the unscoped ones.
+    pub(crate) fn with_scoped_commits_first(mut self, scoped_commits_first: bool) -> Self {
+        self.scoped_commits_first = scoped_commits_first;
+        self
+    }
+
+    /// Render the message of the annotated release tags as the release introduction.
+    pub(crate) fn with_tag_message(mut self, tag_message: bool) -> Self {
+        self.tag_message = tag_message;
+        self
+    }
+
+    /// Title the release of the commits following the latest tag `unreleased_label`.
+    pub(crate) fn with_unreleased_label(mut self, unreleased_label: String) -> Self {
+        self.unreleased_label = unreleased_label;
+        self
+    }
+
+    /// Link each commit of the flat template to the compare range of its release.
+    pub(crate) fn with_compare_links(mut self, compare_links: bool) -> Self {
+        self.compare_links = compare_links;
+        self
+    }
+
+    fn translate_summary<'a>(&'a self, commit: &'a ChangelogCommit) -> &'a str {
+        let oid = commit.commit.oid.as_str();
+        let summary = commit.commit.conventional.summary.as_str();
+        [oid, oid.get(..7).unwrap_or(oid), summary]
+            .into_iter()
+            .find_map(|key| self.summary_translations.get(key))
+            .map_or(summary, String::as_str)
+    }
+
     pub(crate) fn with_package_context(mut self, context: PackageContext) -> Self {
         self.context.extend(context.to_context());
+        self.scope_filter = context.scope_filter;
+        self.include_unscoped = context.include_unscoped;
         self
     }
 
@@ -48,6 +582,13 @@ impl Renderer {
     }
 
     pub(crate) fn render(&mut self, version: Release) -> Result<String, tera::Error> {
+        let version = self.prepare(version);
+
+        // The payload describes a single release
+        if matches!(self.template.kind, TemplateKind::GitlabReleasePayload) {
+            return self.render_release(&version);
+        }
+
         let mut release = self.render_release(&version)?;
         let mut version = version;
         while let Some(previous) = version.previous.map(|v| *v) {
@@ -56,57 +597,3677 @@ impl Renderer {
             version = previous;
         }
 
+        if let Some(max_level) = self.toc_max_level {
+            release.insert_str(0, &Self::table_of_contents(&release, max_level));
+        }
+
+        if let Some(width) = self.wrap_width {
+            release = Self::wrap_list_items(&release, width);
+        }
+
         Ok(release)
     }
 
-    fn render_release(&mut self, version: &Release) -> Result<String, tera::Error> {
-        let release_context = Context::from_serialize(version)?;
-        self.context.extend(release_context);
-        let context = self
-            .template
-            .remote_context
-            .as_ref()
-            .map(RemoteContext::to_context);
+    /// Render the release chain as standalone documents of `per_page` releases each, latest release
+    /// first. Every release keeps its own `from` ref, compare links are not affected by the split.
+    pub(crate) fn render_paginated(
+        &mut self,
+        version: Release,
+        per_page: usize,
+    ) -> Result<Vec<String>, tera::Error> {
+        // Deduplicate and fold the whole chain first so that pages are cut between rendered releases
+        let pages = self.prepare(version).into_pages(per_page);
+        // Only the oldest release of the last page compares against the baseline
+        let last_page = pages.len().saturating_sub(1);
+        let baseline = self.baseline.take();
+        let pages = pages
+            .into_iter()
+            .enumerate()
+            .map(|(idx, page)| {
+                if idx == last_page {
+                    self.baseline.clone_from(&baseline);
+                }
+                self.render(page)
+            })
+            .collect();
+        self.baseline = baseline;
+        pages
+    }
+
+    /// The template contexts of the releases rendered by [`Self::render`], latest release first.
+    pub(crate) fn render_contexts(&mut self, version: Release) -> Result<Vec<Value>, tera::Error> {
+        let version = self.prepare(version);
+        let mut contexts = vec![self.release_context(&version)?.into_json()];
+        if matches!(self.template.kind, TemplateKind::GitlabReleasePayload) {
+            return Ok(contexts);
+        }
 
-        if let Some(context) = context {
-            self.context.extend(context);
+        let mut version = version;
+        while let Some(previous) = version.previous.map(|v| *v) {
+            contexts.push(self.release_context(&previous)?.into_json());
+            version = previous;
         }
 
-        self.tera.render(self.template.kind.name(), &self.context)
+        Ok(contexts)
     }
 
-    // From git-cliff: https://github.com/orhun/git-cliff/blob/main/git-cliff-core/src/template.rs
-    fn upper_first_filter(value: &Value, _: &HashMap<String, Value>) -> Result<Value, tera::Error> {
-        let mut s = tera::try_get_value!("upper_first_filter", "value", String, value);
-        let mut c = s.chars();
-        s = match c.next() {
-            None => String::new(),
-            Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+    fn prepare<'a>(&mut self, version: Release<'a>) -> Release<'a> {
+        self.register_url_functions();
+
+        let version = if self.dedup_releases {
+            version.dedup_versions()
+        } else {
+            version
         };
-        Ok(tera::to_value(&s)?)
-    }
 
-    // filter commit with no scope
-    fn unscoped(value: &Value, args: &HashMap<String, Value>) -> Result<Value, tera::Error> {
-        let mut arr = try_get_value!("unscoped", "scope", Vec<Value>, value);
-        if arr.is_empty() {
-            return Ok(arr.into());
+        let version = if self.fold_pre_releases {
+            version.fold_pre_releases()
+        } else {
+            version
+        };
+
+        let version = match &self.author_allowlist {
+            Some(usernames) => version.retain_authors(usernames),
+            None => version,
+        };
+
+        if self.activity_bar {
+            self.busiest_release =
+                std::iter::successors(Some(&version), |release| release.previous.as_deref())
+                    .map(|release| release.commits.len())
+                    .max()
+                    .unwrap_or_default();
         }
 
-        let value = args.get("value").unwrap_or(&Value::Null);
+        version
+    }
 
-        arr = arr
-            .into_iter()
-            .filter(|v| {
-                let val = dotted_pointer(v, "scope").unwrap_or(&Value::Null);
-                if value.is_null() {
-                    val == value
-                } else {
-                    !val.is_null()
+    // Greedily wrap the list items, keeping links and code spans on a single line
+    fn wrap_list_items(document: &str, width: usize) -> String {
+        let mut wrapped = String::with_capacity(document.len());
+        for line in document.split_inclusive('\n') {
+            let (content, newline) = match line.strip_suffix('\n') {
+                Some(content) => (content, "\n"),
+                None => (line, ""),
+            };
+
+            let indent = content.len() - content.trim_start().len();
+            let text = ["- ", "* ", "+ "]
+                .iter()
+                .find_map(|bullet| content[indent..].strip_prefix(bullet));
+
+            let Some(text) = text.filter(|_| content.chars().count() > width) else {
+                wrapped.push_str(line);
+                continue;
+            };
+
+            // Words of an unclosed link or code span are merged with the following ones
+            let mut words: Vec<String> = vec![];
+            for word in text.split(' ') {
+                match words.last_mut() {
+                    Some(last) if Self::is_unclosed(last) => {
+                        last.push(' ');
+                        last.push_str(word);
+                    }
+                    _ => words.push(word.to_string()),
+                }
+            }
+
+            let prefix = &content[..content.len() - text.len()];
+            let hanging = " ".repeat(prefix.chars().count());
+            let mut current = prefix.to_string();
+            let mut current_width = prefix.chars().count();
+            let mut empty = true;
+            for word in words {
+                let word_width = word.chars().count();
+                if !empty && current_width + 1 + word_width > width {
+                    wrapped.push_str(&current);
+                    wrapped.push('\n');
+                    current = hanging.clone();
+                    current_width = hanging.len();
+                    empty = true;
+                }
+
+                if !empty {
+                    current.push(' ');
+                    current_width += 1;
                 }
+
+                current.push_str(&word);
+                current_width += word_width;
+                empty = false;
+            }
+
+            wrapped.push_str(&current);
+            wrapped.push_str(newline);
+        }
+
+        wrapped
+    }
+
+    fn is_unclosed(text: &str) -> bool {
+        let count = |c: char| text.matches(c).count();
+        count('`') % 2 == 1 || count('[') > count(']') || count('(') > count(')')
+    }
+
+    // Nested list of links to the ATX headings up to the given level, followed by a blank line
+    fn table_of_contents(document: &str, max_level: usize) -> String {
+        static MARKDOWN_LINK: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"\[([^\]]*)\]\([^)]*\)").expect("valid regex"));
+
+        let headings: Vec<(usize, String)> = document
+            .lines()
+            .filter_map(|line| {
+                let level = line.chars().take_while(|c| *c == '#').count();
+                let title = line[level..].strip_prefix(' ')?;
+                (level > 0 && level <= max_level).then(|| {
+                    (
+                        level,
+                        MARKDOWN_LINK.replace_all(title.trim(), "$1").to_string(),
+                    )
+                })
             })
-            .collect::<Vec<_>>();
+            .collect();
 
-        Ok(to_value(arr).unwrap())
+        if headings.is_empty() {
+            return String::new();
+        }
+
+        // Nest by rank so that skipped heading levels do not add indentation
+        let mut levels: Vec<usize> = headings.iter().map(|(level, _)| *level).collect();
+        levels.sort_unstable();
+        levels.dedup();
+
+        // Same anchors as GitHub, duplicated titles get a numbered suffix
+        let mut anchors: HashMap<String, usize> = HashMap::new();
+        let mut toc = String::new();
+        for (level, title) in headings {
+            let anchor: String = title
+                .to_lowercase()
+                .chars()
+                .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+                .map(|c| if c == ' ' { '-' } else { c })
+                .collect();
+            let occurrences = anchors.entry(anchor.clone()).or_default();
+            let anchor = match *occurrences {
+                0 => anchor,
+                n => format!("{anchor}-{n}"),
+            };
+            *occurrences += 1;
+
+            let depth = levels.binary_search(&level).unwrap_or_default();
+            let indent = "  ".repeat(depth);
+            toc.push_str(&format!("{indent}- [{title}](#{anchor})\n"));
+        }
+
+        toc.push('\n');
+        toc
+    }
+
+    /// Render one document per commit type, each listing the commits of that type
+    /// grouped by release. Types without any commit in the whole chain are omitted.
+    pub(crate) fn render_by_type(
+        &mut self,
+        version: Release,
+    ) -> Result<HashMap<CommitType, String>, tera::Error> {
+        let mut documents: HashMap<CommitType, String> = HashMap::new();
+        let mut current = Some(self.prepare(version));
+        // Typed releases are rendered without their predecessors, only the oldest one compares
+        // against the baseline
+        let baseline = self.baseline.take();
+        while let Some(version) = current {
+            if version.previous.is_none() {
+                self.baseline.clone_from(&baseline);
+            }
+
+            let mut commits_by_type: HashMap<CommitType, Vec<ChangelogCommit>> = HashMap::new();
+            for commit in version.commits {
+                commits_by_type
+                    .entry(commit.commit.conventional.commit_type.clone())
+                    .or_default()
+                    .push(commit);
+            }
+
+            for (commit_type, commits) in commits_by_type {
+                let typed_release = Release {
+                    version: version.version.clone(),
+                    from: version.from.clone(),
+                    date: version.date,
+                    commits,
+                    previous: None,
+                    tag_message: version.tag_message.clone(),
+                    tag_aliases: version.tag_aliases.clone(),
+                    diff_stat: None,
+                    timestamp: version.timestamp,
+                    pre_releases: version.pre_releases.clone(),
+                };
+
+                let rendered = self.render_release(&typed_release)?;
+                let document = documents.entry(commit_type).or_default();
+                if !document.is_empty() {
+                    document.push_str("\n- - -\n\n");
+                }
+                document.push_str(&rendered);
+            }
+
+            current = version.previous.map(|previous| *previous);
+        }
+
+        self.baseline = baseline;
+        Ok(documents)
+    }
+
+    /// Render the release chain as colored plain text for terminal previews, following the
+    /// default template layout over the same prepared contexts. Colors are disabled when
+    /// `NO_COLOR` is set.
+    pub(crate) fn render_terminal(&mut self, version: Release) -> Result<String, tera::Error> {
+        let colorize = std::env::var_os("NO_COLOR").is_none();
+        let paint = |text: ColoredString| {
+            if colorize {
+                text.to_string()
+            } else {
+                text.clear().to_string()
+            }
+        };
+        let heading = |title: &str| format!("{}\n", paint(title.bold()));
+        let scope = |commit: &Value| match commit["scope"].as_str() {
+            Some(scope) => format!("{} ", paint(format!("({scope})").dimmed())),
+            None => String::new(),
+        };
+
+        let mut releases = vec![];
+        let mut current = Some(self.prepare(version));
+        while let Some(version) = current {
+            let context = self.release_context(&version)?.into_json();
+            let header = match &version.version {
+                OidOf::Tag(_) => format!(
+                    "{} - {}",
+                    context["version"]["display"].as_str().unwrap_or_default(),
+                    self.release_date(&version)
+                        .format(&self.release_date_format)
+                ),
+                _ => self.unreleased_label.clone(),
+            };
+            let mut release = format!("{}\n", paint(header.bold().underline()));
+
+            let commits = context["commits"].as_array().cloned().unwrap_or_default();
+            if commits.is_empty() {
+                if let Some(placeholder) = &self.empty_release_placeholder {
+                    release.push_str(&format!("{placeholder}\n"));
+                }
+            }
+
+            let highlights = context["highlights"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default();
+            if !highlights.is_empty() {
+                release.push_str(&heading("✨ Highlights"));
+                for highlight in highlights {
+                    release.push_str(&format!("- {}\n", highlight.as_str().unwrap_or_default()));
+                }
+            }
+
+            let breaking_commits = context["breaking_commits"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default();
+            if !breaking_commits.is_empty() {
+                release.push_str(&heading("⚠ BREAKING CHANGES"));
+                for commit in &breaking_commits {
+                    release.push_str(&format!(
+                        "- {}{}\n",
+                        scope(commit),
+                        commit["summary"].as_str().unwrap_or_default()
+                    ));
+                    for note in commit["breaking_notes"].as_array().into_iter().flatten() {
+                        release.push_str(&format!("  {}\n", note.as_str().unwrap_or_default()));
+                    }
+                }
+            }
+
+            for section in context["sections"].as_array().into_iter().flatten() {
+                let section = section.as_str().unwrap_or_default();
+                if section != INLINE_SECTION {
+                    let emoji = self.section_emojis().remove(section).unwrap_or_default();
+                    release.push_str(&heading(&format!("{emoji}{}", Self::upper_first(section))));
+                }
+
+                let mut typed_commits: Vec<&Value> = commits
+                    .iter()
+                    .filter(|commit| commit["group"].as_str() == Some(section))
+                    .collect();
+                if typed_commits.is_empty() {
+                    if let Some(placeholder) = &self.empty_section_placeholder {
+                        release.push_str(&format!("{placeholder}\n"));
+                    }
+                }
+
+                // Scoped commits grouped by scope, then the unscoped ones, like the default template
+                typed_commits.sort_by(|a, b| {
+                    let (a, b) = (a["scope"].as_str(), b["scope"].as_str());
+                    b.is_some().cmp(&a.is_some()).then(a.cmp(&b))
+                });
+
+                for commit in typed_commits {
+                    let shorthand = commit["id"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .chars()
+                        .take(7)
+                        .collect::<String>();
+                    let author = commit["author"]
+                        .as_str()
+                        .or(commit["signature"].as_str())
+                        .unwrap_or_default();
+                    release.push_str(&format!(
+                        "- {}{} - {} - {author}",
+                        scope(commit),
+                        commit["summary"].as_str().unwrap_or_default(),
+                        paint(shorthand.yellow())
+                    ));
+                    match commit["badge"].as_str() {
+                        Some(badge) if !badge.is_empty() => {
+                            release.push_str(&format!(" {badge}\n"))
+                        }
+                        _ => release.push('\n'),
+                    }
+                }
+            }
+
+            for section in context["aggregated_footers"]
+                .as_array()
+                .into_iter()
+                .flatten()
+            {
+                release.push_str(&heading(section["token"].as_str().unwrap_or_default()));
+                for footer in section["footers"].as_array().into_iter().flatten() {
+                    let content = footer["content"].as_str().unwrap_or_default();
+                    match (footer["package"].as_str(), footer["scope"].as_str()) {
+                        (Some(package), _) => {
+                            release.push_str(&format!("- {package} - {content}\n"))
+                        }
+                        (None, Some(scope)) => release.push_str(&format!(
+                            "- {}{content}\n",
+                            paint(format!("({scope}) ").dimmed())
+                        )),
+                        (None, None) => release.push_str(&format!("- {content}\n")),
+                    }
+                }
+            }
+
+            releases.push(release);
+            current = version.previous.map(|previous| *previous);
+        }
+
+        Ok(releases.join("\n- - -\n\n"))
+    }
+
+    /// Render a sorted list of every contributor across the release chain, crediting commit
+    /// authors and co-authors once each, with their remote handle when it is known.
+    pub(crate) fn render_contributors(&self, version: Release) -> String {
+        let mut contributors: BTreeMap<String, (Option<&str>, usize)> = BTreeMap::new();
+        let mut current = Some(&version);
+        while let Some(version) = current {
+            for commit in &version.commits {
+                let author = contributors
+                    .entry(commit.commit.author.clone())
+                    .or_default();
+                author.0 = author.0.or(commit.author_username);
+                author.1 += 1;
+                for co_author in commit.co_authors() {
+                    contributors.entry(co_author.to_string()).or_default().1 += 1;
+                }
+            }
+
+            current = version.previous.as_deref();
+        }
+
+        let user_base_url = self
+            .template
+            .remote_context
+            .as_ref()
+            .map(RemoteContext::user_base_url);
+
+        contributors
+            .into_iter()
+            .map(|(name, (handle, count))| {
+                let mut line = match (handle, &user_base_url) {
+                    (Some(handle), Some(user_base_url)) => {
+                        format!("- {name} ([@{handle}]({user_base_url}{handle}))")
+                    }
+                    (Some(handle), None) => format!("- {name} (@{handle})"),
+                    (None, _) => format!("- {name}"),
+                };
+
+                if self.contributor_counts {
+                    let count = Self::group_digits(
+                        count as u64,
+                        self.thousands_separator.as_deref().unwrap_or_default(),
+                    );
+                    line.push_str(&format!(" - {count} commits"));
+                }
+
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+
+    fn render_release(&self, version: &Release) -> Result<String, tera::Error> {
+        let context = self.release_context(version)?;
+        let rendered = self.tera.render(self.template.kind.name(), &context)?;
+        Ok(match self.heading_syntax {
+            HeadingSyntax::Atx => rendered,
+            HeadingSyntax::Setext => Self::setext_headings(&rendered),
+        })
+    }
+
+    // The release date in the configured timezone, the commit date otherwise
+    fn release_date(&self, version: &Release) -> NaiveDateTime {
+        match (self.timezone, version.timestamp) {
+            (Some(timezone), Some(timestamp)) => timestamp.with_timezone(&timezone).naive_local(),
+            _ => version.date,
+        }
+    }
+
+    // Chrono panics when displaying a date with an unknown specifier, reject the format upfront
+    fn check_date_format(format: &str) -> Result<(), tera::Error> {
+        if StrftimeItems::new(format).any(|item| item == Item::Error) {
+            return Err(tera::Error::chain(
+                "failed to render changelog dates",
+                ChangelogError::InvalidDateFormat(format.to_string()),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn release_context(&self, version: &Release) -> Result<Context, tera::Error> {
+        Self::check_date_format(&self.release_date_format)?;
+        if self.commit_line_suffix.is_some() {
+            Self::check_date_format(&self.date_format)?;
+        }
+        let mut context = self.context.clone();
+        let mut release = to_value(version)?;
+        for (key, oid) in [("version", &version.version), ("from", &version.from)] {
+            release[key]["display"] = Value::from(if self.strip_tag_prefixes {
+                oid.display_for(self.template.kind.tag_display_context())
+            } else {
+                oid.to_string()
+            });
+        }
+        if let (OidOf::FirstCommit(_), Some(label)) = (&version.from, &self.initial_version_label) {
+            release["from"]["label"] = Value::from(label.as_str());
+        }
+        // The oldest release of the document compares against the baseline, whatever its range
+        if let (None, Some(baseline)) = (&version.previous, &self.baseline) {
+            release["from"]["type"] = Value::from("tag");
+            release["from"]["tag"] = Value::from(baseline.as_str());
+            release["from"]["display"] = Value::from(baseline.as_str());
+        }
+        if let Some(Value::Array(commits)) = release.get_mut("commits") {
+            for (commit, changelog_commit) in commits.iter_mut().zip(&version.commits) {
+                commit["badge"] = Value::from(self.badge(changelog_commit));
+                if let Some(suffix) = &self.commit_line_suffix {
+                    commit["line_suffix"] = Value::from(self.line_suffix(suffix, changelog_commit));
+                }
+                if matches!(self.template.kind, TemplateKind::GitlabReleasePayload) {
+                    commit["gitlab_category"] = Value::from(Self::gitlab_category(
+                        &changelog_commit.commit.conventional.commit_type,
+                    ));
+                }
+                commit["author_link"] = self
+                    .author_link(changelog_commit)
+                    .map_or(Value::Null, Value::from);
+                commit["breaking_notes"] = Value::from(Self::breaking_notes(changelog_commit));
+                let summary = self.style_summary(self.translate_summary(changelog_commit));
+                commit["summary"] = Value::from(if changelog_commit.reverted {
+                    format!("~~{summary}~~")
+                } else {
+                    summary
+                });
+                commit["group"] = self
+                    .commit_group(changelog_commit)
+                    .filter(|_| self.has_required_label(changelog_commit))
+                    .filter(|_| self.matches_scope_filter(changelog_commit))
+                    .map_or(Value::Null, Value::from);
+            }
+
+            commits.retain(|commit| !commit["group"].is_null());
+            // Stable, the release order is kept among the scoped and the unscoped commits
+            if self.scoped_commits_first {
+                commits.sort_by_key(|commit| commit["scope"].is_null());
+            }
+            if self.scope_counts && self.grouping.key == GroupBy::Scope {
+                Self::count_scope_groups(commits);
+            }
+            if self.grouping.key == GroupBy::Author {
+                Self::collapse_minor_authors(commits, self.grouping.min_commits_for_own_section);
+            }
+        }
+        self.link_issues(&mut release);
+        let breaking_commits: Vec<Value> = release["commits"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|commit| commit["breaking_change"] == Value::Bool(true))
+            .cloned()
+            .collect();
+        context.insert("breaking_commits", &breaking_commits);
+        context.insert("breaking_groups", &self.breaking_groups(&breaking_commits));
+        context.insert("highlights", &Self::highlights(&release));
+        let release_context = Context::from_value(release)?;
+        let sections = self.sections(&release_context, "group");
+        if matches!(self.template.kind, TemplateKind::GitlabReleasePayload) {
+            context.insert(
+                "gitlab_categories",
+                &self.sections(&release_context, "gitlab_category"),
+            );
+        }
+        let type_sections = self.sections(&release_context, "type");
+        if matches!(self.template.kind, TemplateKind::ScopeGrouped) {
+            if let Some(Value::Array(commits)) = release_context.get("commits") {
+                context.insert("scope_groups", &self.scope_groups(commits, &type_sections));
+            }
+        }
+        let type_codes = self.type_codes(&type_sections);
+        context.extend(release_context);
+        context.insert("date", &self.release_date(version));
+        let footer_sections = self.footer_sections(version);
+        // The built-in templates open the collapsed block before the first internal section
+        let internal_sections = self.internal_sections();
+        let internal_sections_start = match self.grouping.key {
+            GroupBy::Type => sections
+                .iter()
+                .find(|title| internal_sections.contains(*title))
+                .map(String::as_str),
+            _ => None,
+        };
+        context.insert(
+            "internal_sections_start",
+            internal_sections_start.unwrap_or_default(),
+        );
+        context.insert("sections", &sections);
+        context.insert("inline_section", INLINE_SECTION);
+        context.insert(
+            "empty_section_placeholder",
+            self.empty_section_placeholder
+                .as_deref()
+                .unwrap_or_default(),
+        );
+        context.insert(
+            "empty_release_placeholder",
+            self.empty_release_placeholder
+                .as_deref()
+                .unwrap_or_default(),
+        );
+        context.insert("type_codes", &type_codes);
+        context.insert("section_emojis", &self.section_emojis());
+        context.insert("scope_format", &self.scope_format);
+        context.insert("footer_style", &self.footer_style);
+        context.insert(
+            "hash_position",
+            &self
+                .hash_position
+                .unwrap_or_else(|| self.template.kind.default_hash_position()),
+        );
+        let mut footer_sections = to_value(&footer_sections)?;
+        self.link_issues(&mut footer_sections);
+        context.insert("aggregated_footers", &footer_sections);
+        context.insert(
+            "header_prefix",
+            match self.header_style {
+                HeaderStyle::Markdown => "## ",
+                HeaderStyle::Plain => "",
+            },
+        );
+        // Package templates nest the type sections one level below the package headings
+        context.insert("package_heading", &"#".repeat(self.package_heading_level));
+        context.insert(
+            "section_heading",
+            &"#".repeat(self.package_heading_level + 1),
+        );
+        context.insert("contributors", &version.contributor_tally());
+        if matches!(self.template.kind, TemplateKind::DefaultWithContributors) {
+            context.insert("contributor_list", &version.contributors());
+        }
+        context.insert(
+            "thousands_separator",
+            self.thousands_separator.as_deref().unwrap_or_default(),
+        );
+        context.insert("commit_url_template", &self.commit_url_template);
+        context.insert("date_format", &self.date_format);
+        context.insert("release_date_format", &self.release_date_format);
+        let deployment_url = match (&self.deployment_url_template, &version.version) {
+            (Some(url_template), OidOf::Tag(tag)) => {
+                url_template.replace("{version}", &tag.version.to_string())
+            }
+            _ => String::new(),
+        };
+        context.insert("deployment_url", &deployment_url);
+        context.insert("milestone_token", &self.milestone_token);
+        context.insert("unreleased_compare_ref", &self.unreleased_compare_ref);
+        context.insert("unreleased_label", &self.unreleased_label);
+        // A repository without tags, the release spans the whole history
+        context.insert(
+            "untagged",
+            &(matches!(version.from, OidOf::FirstCommit(_))
+                && !matches!(version.version, OidOf::Tag(_))
+                && version.previous.is_none()),
+        );
+        context.insert("compare_links", &self.compare_links);
+        context.insert("diff_stat", &version.diff_stat);
+        context.insert("activity_bar", &self.activity_bar(version.commits.len()));
+        context.insert("pre_releases", &version.pre_releases);
+        match &version.tag_message {
+            Some(message) if self.tag_message => context.insert("tag_message", message),
+            _ => context.insert("tag_message", ""),
+        }
+        match self.same_commit_tags {
+            SameCommitTags::Merge => context.insert("tag_aliases", &version.tag_aliases),
+            SameCommitTags::Skip => context.insert("tag_aliases", &Vec::<String>::new()),
+        }
+        match self.author_style {
+            Some(author_style) => context.insert("author_style", &author_style),
+            None => context.insert("author_style", ""),
+        }
+        let remote_context = self
+            .template
+            .remote_context
+            .as_ref()
+            .map(RemoteContext::to_context);
+
+        if let Some(remote_context) = remote_context {
+            context.extend(remote_context);
+        }
+
+        Ok(context)
+    }
+
+    // `commit_url(sha=...)`, `compare_url(from=..., to=...)` and `history_url(to=...)`,
+    // honoring the remote url formats
+    fn register_url_functions(&mut self) {
+        let Some(remote) = self.template.remote_context.clone() else {
+            return;
+        };
+
+        let arg = |args: &HashMap<String, Value>, name: &str| -> tera::Result<String> {
+            match args.get(name) {
+                Some(Value::String(value)) => Ok(value.clone()),
+                Some(value) => Ok(value.to_string()),
+                None => Err(tera::Error::msg(format!("missing url argument `{name}`"))),
+            }
+        };
+
+        let commit_remote = remote.clone();
+        let history_remote = remote.clone();
+        self.tera
+            .register_function("history_url", move |args: &HashMap<String, Value>| {
+                Ok(Value::from(history_remote.history_url(&arg(args, "to")?)))
+            });
+        self.tera
+            .register_function("commit_url", move |args: &HashMap<String, Value>| {
+                Ok(Value::from(commit_remote.commit_url(&arg(args, "sha")?)))
+            });
+        self.tera
+            .register_function("compare_url", move |args: &HashMap<String, Value>| {
+                let (from, to) = (arg(args, "from")?, arg(args, "to")?);
+                Ok(Value::from(remote.compare_url(&from, &to)))
+            });
+    }
+
+    // Custom `Highlight` footer texts, or the summary of the commits highlighted with `true`
+    fn highlights(release: &Value) -> Vec<Value> {
+        release["commits"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|commit| {
+                let footer = commit["footer"].as_array()?.iter().find(|footer| {
+                    footer["token"]
+                        .as_str()
+                        .is_some_and(|token| token.eq_ignore_ascii_case(HIGHLIGHT_TOKEN))
+                })?;
+
+                match footer["content"].as_str()?.trim() {
+                    "true" => Some(commit["summary"].clone()),
+                    "false" | "" => None,
+                    text => Some(Value::from(text)),
+                }
+            })
+            .collect()
+    }
+
+    // Underline level 1 and 2 ATX headings
+    fn setext_headings(rendered: &str) -> String {
+        rendered
+            .split_inclusive('\n')
+            .map(|line| {
+                let (title, underline) = if let Some(title) = line.strip_prefix("# ") {
+                    (title, '=')
+                } else if let Some(title) = line.strip_prefix("## ") {
+                    (title, '-')
+                } else {
+                    return line.to_string();
+                };
+
+                let title = title.trim_end();
+                let underline = underline.to_string().repeat(title.chars().count());
+                format!("{title}\n{underline}\n")
+            })
+            .collect()
+    }
+
+    // Replace issue references in every commit summary and footer content with tracker links
+    fn link_issues(&self, value: &mut Value) {
+        let Some(issue_reference) = &self.issue_reference else {
+            return;
+        };
+
+        match value {
+            Value::Array(values) => values.iter_mut().for_each(|value| self.link_issues(value)),
+            Value::Object(object) => {
+                for (key, value) in object.iter_mut() {
+                    match (key.as_str(), value) {
+                        ("summary" | "content", Value::String(text)) => {
+                            *text = issue_reference
+                                .replace_all(text, |captures: &Captures| {
+                                    let url = self.issue_trackers[&captures[2]]
+                                        .replace("{id}", &captures[3]);
+                                    format!(
+                                        "{}[{}{}]({url})",
+                                        &captures[1], &captures[2], &captures[3]
+                                    )
+                                })
+                                .into_owned();
+                        }
+                        (_, value) => self.link_issues(value),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn badge(&self, commit: &ChangelogCommit) -> String {
+        let mut badges = vec![self.signature_badge(commit).to_string()];
+        let pull_requests = PULL_REQUEST
+            .captures_iter(&commit.commit.conventional.summary)
+            .map(|captures| captures[1].to_string());
+
+        let mut labels: Vec<&String> = std::iter::once(commit.commit.oid.clone())
+            .chain(pull_requests)
+            .filter_map(|key| self.commit_labels.get(&key))
+            .flatten()
+            .collect();
+        labels.dedup();
+        badges.extend(labels.into_iter().map(|label| format!("`{label}`")));
+        badges.retain(|badge| !badge.is_empty());
+        badges.join(" ")
+    }
+
+    // The configured commit line tail, with its `{hash}`, `{author}`, `{date}` and `{pr}` placeholders
+    // replaced, the author falls back to the signature name and `{pr}` to an empty string
+    fn line_suffix(&self, suffix: &str, commit: &ChangelogCommit) -> String {
+        let oid = &commit.commit.oid;
+        let pull_request = PULL_REQUEST
+            .find(&commit.commit.conventional.summary)
+            .map_or("", |pull_request| pull_request.as_str());
+
+        suffix
+            .replace("{hash}", oid.get(..7).unwrap_or(oid))
+            .replace(
+                "{author}",
+                commit.author_username.unwrap_or(&commit.commit.author),
+            )
+            .replace(
+                "{date}",
+                &commit.commit.date.format(&self.date_format).to_string(),
+            )
+            .replace("{pr}", pull_request)
+    }
+
+    // Content of the breaking change footers, continuation lines indented under the entry
+    fn breaking_notes(commit: &ChangelogCommit) -> Vec<String> {
+        commit
+            .commit
+            .conventional
+            .footers
+            .iter()
+            .filter(|footer| footer.is_breaking_change())
+            .map(|footer| footer.content.trim().replace('\n', "\n  "))
+            .collect()
+    }
+
+    // Changelog category of the GitLab release payload entries
+    fn gitlab_category(commit_type: &CommitType) -> &'static str {
+        match commit_type {
+            CommitType::Feature => "added",
+            CommitType::BugFix => "fixed",
+            CommitType::Performances => "performance",
+            CommitType::Refactor | CommitType::Revert => "changed",
+            _ => "other",
+        }
+    }
+
+    // Link for the authors without username, following `author_link_fallback`
+    fn author_link(&self, commit: &ChangelogCommit) -> Option<String> {
+        if commit.author_url.is_some() {
+            return commit.author_url.clone();
+        }
+
+        if commit.author_username.is_some() {
+            return None;
+        }
+
+        let email = commit.author_email.as_deref()?;
+        match &self.author_link_fallback {
+            AuthorLinkFallback::None => None,
+            AuthorLinkFallback::Mailto => Some(format!("mailto:{email}")),
+            AuthorLinkFallback::Search(url) => Some(
+                url.replace("{email}", email)
+                    .replace("{name}", &commit.commit.author),
+            ),
+        }
+    }
+
+    fn signature_badge(&self, commit: &ChangelogCommit) -> &'static str {
+        let Some(trusted_keys) = &self.trusted_keys else {
+            return "";
+        };
+
+        match &commit.commit_signature {
+            Some(signature) if signature.is_trusted_by(trusted_keys) => "✔",
+            Some(_) => "🔒",
+            None => "",
+        }
+    }
+
+    // Section name of a commit, `None` when the commit is left out of the release
+    fn commit_group(&self, commit: &ChangelogCommit) -> Option<String> {
+        let conventional = &commit.commit.conventional;
+        let commit_type = self.type_section(&conventional.commit_type);
+        let group = match &self.grouping.key {
+            GroupBy::Type => return Some(commit_type),
+            GroupBy::Scope => conventional.scope.clone(),
+            GroupBy::Author => Some(
+                commit
+                    .author_username
+                    .map_or_else(|| commit.commit.author.clone(), str::to_string),
+            ),
+            GroupBy::Footer(token) => conventional
+                .footers
+                .iter()
+                .find(|footer| &footer.token == token)
+                .map(|footer| footer.content.clone()),
+        };
+
+        group.or_else(|| match &self.grouping.fallback {
+            GroupFallback::Bucket(name) => Some(name.clone()),
+            GroupFallback::Type => Some(commit_type),
+            GroupFallback::Omit => None,
+            GroupFallback::Inline => Some(INLINE_SECTION.to_string()),
+        })
+    }
+
+    // Block level of the release relative to the busiest release of the chain, empty when disabled
+    fn activity_bar(&self, commits: usize) -> String {
+        const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+        if !self.activity_bar || commits == 0 || self.busiest_release == 0 {
+            return String::new();
+        }
+
+        let level = (commits * LEVELS.len()).div_ceil(self.busiest_release);
+        LEVELS[level.min(LEVELS.len()) - 1].to_string()
+    }
+
+    // Move the authors with fewer than `min_commits` commits to the `Others` group
+    fn collapse_minor_authors(commits: &mut [Value], min_commits: usize) {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for group in commits.iter().filter_map(|commit| commit["group"].as_str()) {
+            *counts.entry(group.to_string()).or_default() += 1;
+        }
+
+        for commit in commits.iter_mut() {
+            let group = commit["group"].as_str().unwrap_or_default();
+            if counts.get(group).is_some_and(|count| *count < min_commits) {
+                commit["group"] = Value::from(OTHERS_SECTION);
+            }
+        }
+    }
+
+    // Suffix the scope groups with their number of commits, fallback groups are left as is
+    fn count_scope_groups(commits: &mut [Value]) {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for commit in commits.iter().filter(|commit| !commit["scope"].is_null()) {
+            if let Some(group) = commit["group"].as_str() {
+                *counts.entry(group.to_string()).or_default() += 1;
+            }
+        }
+
+        for commit in commits
+            .iter_mut()
+            .filter(|commit| !commit["scope"].is_null())
+        {
+            let group = commit["group"].as_str().unwrap_or_default().to_string();
+            if let Some(count) = counts.get(&group) {
+                commit["group"] = Value::from(format!("{group} ({count})"));
+            }
+        }
+    }
+
+    // Emoji prefixes of the commit type section headings, keyed by section title
+    fn section_emojis(&self) -> HashMap<String, String> {
+        COMMITS_METADATA
+            .iter()
+            .filter_map(|(commit_type, config)| {
+                let emoji = config.emoji.as_ref()?;
+                Some((self.type_section(commit_type), format!("{emoji} ")))
+            })
+            .collect()
+    }
+
+    fn type_section(&self, commit_type: &CommitType) -> String {
+        let title = changelog_title(commit_type);
+        let shared = COMMITS_METADATA
+            .iter()
+            .any(|(other, config)| other != commit_type && config.changelog_title == title);
+
+        if shared && !self.merge_duplicate_sections {
+            format!("{title} ({commit_type})")
+        } else {
+            title
+        }
+    }
+
+    fn style_summary(&self, summary: &str) -> String {
+        let sentence = || Self::upper_first(summary.trim_end().trim_end_matches('.'));
+        match self.summary_style {
+            SummaryStyle::AsIs => summary.to_string(),
+            SummaryStyle::SentenceCase => sentence(),
+            SummaryStyle::SentenceCaseWithPeriod => {
+                let sentence = sentence();
+                if sentence.ends_with(['!', '?']) {
+                    sentence
+                } else {
+                    sentence + "."
+                }
+            }
+        }
+    }
+
+    fn has_required_label(&self, commit: &ChangelogCommit) -> bool {
+        let Some((token, label)) = &self.required_label else {
+            return true;
+        };
+
+        commit
+            .commit
+            .conventional
+            .footers
+            .iter()
+            .filter(|footer| &footer.token == token)
+            .any(|footer| footer.content.split(',').any(|value| value.trim() == label))
+    }
+
+    fn matches_scope_filter(&self, commit: &ChangelogCommit) -> bool {
+        let Some(scopes) = &self.scope_filter else {
+            return true;
+        };
+
+        match &commit.commit.conventional.scope {
+            Some(scope) => scopes.contains(scope),
+            None => self.include_unscoped,
+        }
+    }
+
+    // Distinct values of the given commit attribute, in the order their sections should be rendered
+    fn sections(&self, release_context: &Context, key: &str) -> Vec<String> {
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        if let Some(Value::Array(commits)) = release_context.get("commits") {
+            for commit_type in commits.iter().filter_map(|commit| commit[key].as_str()) {
+                *counts.entry(commit_type.to_string()).or_default() += 1;
+            }
+        }
+
+        // The release placeholder replaces the whole body of a release without commits
+        let placeholder = counts.is_empty()
+            && self
+                .empty_release_placeholder
+                .as_ref()
+                .is_some_and(|placeholder| !placeholder.is_empty());
+        if key == "group" && self.grouping.key == GroupBy::Type && !placeholder {
+            for commit_type in &self.always_show_types {
+                counts.entry(self.type_section(commit_type)).or_default();
+            }
+        }
+
+        let mut sections: Vec<(String, usize)> = counts.into_iter().collect();
+        // Sections with as many commits keep their position in the default `alphabetical` order
+        if self.type_order == TypeOrder::ByCount {
+            sections
+                .sort_by(|(a_title, a), (b_title, b)| b.cmp(a).then_with(|| a_title.cmp(b_title)));
+        }
+
+        // Listed sections come first, in the configured order
+        sections.sort_by_key(|(title, _)| {
+            self.section_order
+                .iter()
+                .position(|listed| listed == title)
+                .unwrap_or(self.section_order.len())
+        });
+
+        // Inlined commits are rendered before any section heading
+        sections.sort_by_key(|(title, _)| title != INLINE_SECTION);
+
+        if key == "group" && self.grouping.key == GroupBy::Author {
+            sections.sort_by_key(|(title, _)| title == OTHERS_SECTION);
+        }
+
+        if key == "group" && self.grouping.key == GroupBy::Type {
+            let internal = self.internal_sections();
+            sections.sort_by_key(|(title, _)| internal.contains(title));
+        }
+
+        sections.into_iter().map(|(title, _)| title).collect()
+    }
+
+    fn internal_sections(&self) -> HashSet<String> {
+        self.internal_types
+            .iter()
+            .map(|commit_type| self.type_section(commit_type))
+            .collect()
+    }
+
+    fn type_codes(&self, sections: &[String]) -> HashMap<String, String> {
+        let codes: HashMap<String, &String> = self
+            .type_abbreviations
+            .iter()
+            .map(|(commit_type, code)| (changelog_title(commit_type), code))
+            .collect();
+
+        sections
+            .iter()
+            .map(|title| {
+                let code = match codes.get(title) {
+                    Some(code) => code.to_string(),
+                    None => title.chars().take(1).flat_map(char::to_uppercase).collect(),
+                };
+
+                (title.clone(), code)
+            })
+            .collect()
+    }
+
+    // Commits grouped by scope then by type, scopeless commits go to the grouping fallback:
+    // a trailing bucket, a leading group without heading, or nowhere
+    fn scope_groups<'a>(
+        &'a self,
+        commits: &'a [Value],
+        type_sections: &'a [String],
+    ) -> Vec<ScopeGroup<'a>> {
+        let mut scopes: BTreeMap<&str, Vec<&Value>> = BTreeMap::new();
+        let mut unscoped = vec![];
+        for commit in commits {
+            match commit["scope"].as_str() {
+                Some(scope) => scopes.entry(scope).or_default().push(commit),
+                None => unscoped.push(commit),
+            }
+        }
+
+        let mut groups: Vec<(&str, Vec<&Value>)> = scopes.into_iter().collect();
+        match &self.grouping.fallback {
+            GroupFallback::Bucket(name) => groups.push((name.as_str(), unscoped)),
+            GroupFallback::Type | GroupFallback::Inline => groups.insert(0, ("", unscoped)),
+            GroupFallback::Omit => {}
+        }
+
+        groups
+            .into_iter()
+            .filter(|(_, commits)| !commits.is_empty())
+            .map(|(scope, commits)| ScopeGroup {
+                scope,
+                sections: type_sections
+                    .iter()
+                    .map(|title| ScopeSection {
+                        title: title.as_str(),
+                        commits: commits
+                            .iter()
+                            .copied()
+                            .filter(|commit| commit["type"].as_str() == Some(title.as_str()))
+                            .collect(),
+                    })
+                    .filter(|section| !section.commits.is_empty())
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn footer_sections<'a>(&'a self, version: &'a Release) -> Vec<FooterSection<'a>> {
+        self.aggregated_footers
+            .iter()
+            .map(|token| {
+                let mut footers: Vec<ChangelogFooter> = vec![];
+                let matching = version.commits.iter().flat_map(|commit| {
+                    let conventional = &commit.commit.conventional;
+                    conventional
+                        .footers
+                        .iter()
+                        .filter(|footer| &footer.token == token)
+                        .map(|footer| {
+                            let mut changelog_footer = ChangelogFooter::from(footer);
+                            if self.breaking_change_scopes && footer.is_breaking_change() {
+                                self.attribute_footer(
+                                    &mut changelog_footer,
+                                    conventional.scope.as_deref(),
+                                );
+                            }
+                            changelog_footer
+                        })
+                });
+
+                for footer in matching {
+                    if !footers.contains(&footer) {
+                        footers.push(footer);
+                    }
+                }
+
+                FooterSection {
+                    token: token.as_str(),
+                    footers,
+                }
+            })
+            .filter(|section| !section.footers.is_empty())
+            .collect()
+    }
+
+    // Set the footer package when the scope names a package bumped by the monorepo release
+    fn attribute_footer<'a>(&self, footer: &mut ChangelogFooter<'a>, scope: Option<&'a str>) {
+        match scope {
+            Some(scope) if self.package_names().any(|name| name == scope) => {
+                footer.package = Some(scope)
+            }
+            scope => footer.scope = scope,
+        }
+    }
+
+    // Names of the packages bumped by the monorepo release, empty outside of monorepo templates
+    fn package_names(&self) -> impl Iterator<Item = &str> {
+        self.context
+            .get("packages")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|package| package["package_name"].as_str())
+    }
+
+    // Breaking commits whose scope names a bumped package are grouped under that package,
+    // in package order, after the group of the other breaking commits
+    fn breaking_groups<'a>(&'a self, breaking_commits: &'a [Value]) -> Vec<BreakingGroup<'a>> {
+        let packages: Vec<&str> = match self.breaking_change_grouping {
+            BreakingChangeGrouping::Flat => vec![],
+            BreakingChangeGrouping::Package => self.package_names().collect(),
+        };
+        let package_of = |commit: &'a Value| {
+            commit["scope"]
+                .as_str()
+                .filter(|scope| packages.contains(scope))
+        };
+
+        let mut groups = vec![BreakingGroup {
+            package: None,
+            commits: breaking_commits
+                .iter()
+                .filter(|commit| package_of(commit).is_none())
+                .collect(),
+        }];
+        groups.extend(packages.iter().map(|&package| {
+            BreakingGroup {
+                package: Some(package),
+                commits: breaking_commits
+                    .iter()
+                    .filter(|commit| package_of(commit) == Some(package))
+                    .collect(),
+            }
+        }));
+        groups.retain(|group| !group.commits.is_empty());
+        groups
+    }
+
+    // From git-cliff: https://github.com/orhun/git-cliff/blob/main/git-cliff-core/src/template.rs
+    fn upper_first_filter(value: &Value, _: &HashMap<String, Value>) -> Result<Value, tera::Error> {
+        let s = tera::try_get_value!("upper_first_filter", "value", String, value);
+        Ok(tera::to_value(Self::upper_first(&s))?)
+    }
+
+    fn upper_first(s: &str) -> String {
+        let mut c = s.chars();
+        match c.next() {
+            None => String::new(),
+            Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+        }
+    }
+
+    // group the digits of a count with the given separator
+    fn format_count(value: &Value, args: &HashMap<String, Value>) -> Result<Value, tera::Error> {
+        let count = try_get_value!("format_count", "value", u64, value);
+        let separator = match args.get("separator") {
+            Some(separator) => try_get_value!("format_count", "separator", String, separator),
+            None => String::new(),
+        };
+
+        Ok(to_value(Self::group_digits(count, &separator))?)
+    }
+
+    fn group_digits(count: u64, separator: &str) -> String {
+        let digits = count.to_string();
+        let mut grouped = String::with_capacity(digits.len() * (separator.len() + 1));
+        for (idx, digit) in digits.chars().enumerate() {
+            if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+                grouped.push_str(separator);
+            }
+            grouped.push(digit);
+        }
+
+        grouped
+    }
+
+    // filter commit with no scope
+    fn unscoped(value: &Value, args: &HashMap<String, Value>) -> Result<Value, tera::Error> {
+        let mut arr = try_get_value!("unscoped", "scope", Vec<Value>, value);
+        if arr.is_empty() {
+            return Ok(arr.into());
+        }
+
+        let value = args.get("value").unwrap_or(&Value::Null);
+
+        arr = arr
+            .into_iter()
+            .filter(|v| {
+                let val = dotted_pointer(v, "scope").unwrap_or(&Value::Null);
+                if value.is_null() {
+                    val == value
+                } else {
+                    !val.is_null()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Ok(to_value(arr).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{HashMap, HashSet};
+
+    use anyhow::Result;
+    use conventional_commit_parser::commit::{CommitType, Footer};
+    use git2::Oid;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use crate::conventional::changelog::error::ChangelogError;
+    use crate::conventional::changelog::release::{ChangelogCommit, Release};
+    use crate::conventional::changelog::renderer::Renderer;
+    use crate::conventional::changelog::template::{RemoteContext, Template, TemplateKind};
+    use crate::git::oid::OidOf;
+    use crate::git::signature::CommitSignature;
+    use crate::git::tag::Tag;
+    use crate::settings::{
+        AuthorLinkFallback, AuthorStyle, FooterStyle, GroupBy, GroupFallback, Grouping,
+        HashPosition, HeaderStyle, HeadingSyntax, ScopeFormat, SummaryStyle, TypeOrder,
+    };
+
+    #[test]
+    fn should_render_commit_url_template_without_remote() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default()
+            .with_commit_url_template(Some("vscode://vscode.git/commit/{sha}".to_string()));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - ([17f7e23](vscode://vscode.git/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - ([17f7e23](vscode://vscode.git/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - *oknozor*
+                - awesome feature - ([17f7e23](vscode://vscode.git/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_expose_template_contexts_with_remote_fields() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".to_string()),
+                Some("cocogitto".to_string()),
+                Some("cocogitto".to_string()),
+            ),
+            kind: TemplateKind::Remote,
+        })?;
+
+        // Act
+        let contexts = renderer.render_contexts(release)?;
+
+        // Assert
+        assert_eq!(contexts.len(), 1);
+        let context = &contexts[0];
+        assert_eq!(
+            context["repository_url"],
+            tera::Value::from("https://github.com/cocogitto/cocogitto")
+        );
+        assert_eq!(context["version"]["display"], tera::Value::from("1.0.0"));
+        assert_eq!(
+            context["sections"],
+            tera::Value::from(vec!["Bug Fixes", "Features"])
+        );
+        assert_eq!(
+            context["commits"][0]["summary"],
+            tera::Value::from("fix parser implementation")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_link_first_release_to_the_history_instead_of_a_compare_url() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.from =
+            OidOf::FirstCommit(Oid::from_str("a4ff5f1e0e2b3c1b5a4b5e3a6f7e8d9c0b1a2f3e")?);
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".to_string()),
+                Some("cocogitto".to_string()),
+                Some("cocogitto".to_string()),
+            ),
+            kind: TemplateKind::Remote,
+        })?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.starts_with(
+            "## [1.0.0](https://github.com/cocogitto/cocogitto/commits/1.0.0) - 2015-09-05\n"
+        ));
+
+        Ok(())
+    }
+
+    fn with_v_prefixed_tags(release: &mut Release) {
+        for oid in [&mut release.version, &mut release.from] {
+            if let OidOf::Tag(tag) = oid {
+                tag.prefix = Some("v".to_string());
+            }
+        }
+    }
+
+    fn github_remote_renderer() -> Result<Renderer> {
+        Ok(Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".to_string()),
+                Some("cocogitto".to_string()),
+                Some("cocogitto".to_string()),
+            ),
+            kind: TemplateKind::Remote,
+        })?)
+    }
+
+    #[test]
+    fn should_display_prefixed_tags_in_headers_and_link_to_the_tag_refs() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        with_v_prefixed_tags(&mut release);
+        let mut simple_release = Release::fixture();
+        with_v_prefixed_tags(&mut simple_release);
+        let mut renderer = github_remote_renderer()?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+        let simple = Renderer::default().render(simple_release)?;
+
+        // Assert
+        assert!(changelog.starts_with(
+            "## [v1.0.0](https://github.com/cocogitto/cocogitto/compare/v0.1.0..v1.0.0) - 2015-09-05\n"
+        ));
+        assert!(simple.starts_with("## v1.0.0 - 2015-09-05\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_link_to_prefixed_tag_refs_when_stripping_tag_prefixes() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        with_v_prefixed_tags(&mut release);
+        let mut renderer = github_remote_renderer()?.with_strip_tag_prefixes(true);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.starts_with(
+            "## [1.0.0](https://github.com/cocogitto/cocogitto/compare/v0.1.0..v1.0.0) - 2015-09-05\n"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_link_first_release_to_the_prefixed_tag_history() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        with_v_prefixed_tags(&mut release);
+        release.from =
+            OidOf::FirstCommit(Oid::from_str("a4ff5f1e0e2b3c1b5a4b5e3a6f7e8d9c0b1a2f3e")?);
+        let mut renderer = github_remote_renderer()?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.starts_with(
+            "## [v1.0.0](https://github.com/cocogitto/cocogitto/commits/v1.0.0) - 2015-09-05\n"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_remote_links_from_url_formats() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let remote_context = RemoteContext::try_new(
+            Some("git.example.com".to_string()),
+            Some("cocogitto".to_string()),
+            Some("cocogitto".to_string()),
+        )
+        .map(|context| {
+            context.with_url_formats(
+                Some("https://proxy.example.com/{owner}/{repo}/c/{sha}".to_string()),
+                Some(
+                    "https://proxy.example.com/{owner}/{repo}/diff?from={from}&to={to}".to_string(),
+                ),
+            )
+        });
+        let mut renderer = Renderer::try_new(Template {
+            remote_context,
+            kind: TemplateKind::Remote,
+        })?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.starts_with(
+            "## [1.0.0](https://proxy.example.com/cocogitto/cocogitto/diff?from=0.1.0&to=1.0.0) - 2015-09-05\n"
+        ));
+        assert!(changelog.contains(
+            "([17f7e23](https://proxy.example.com/cocogitto/cocogitto/c/17f7e23081db15e9318aeb37529b1d473cf41cbe))"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_deployment_link_in_release_header() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".to_string()),
+                Some("cocogitto".to_string()),
+                Some("cocogitto".to_string()),
+            ),
+            kind: TemplateKind::Remote,
+        })?
+        .with_deployment_url_template(Some(
+            "https://app.example.com/releases/{version}".to_string(),
+        ));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.starts_with(
+            "## [1.0.0](https://github.com/cocogitto/cocogitto/compare/0.1.0..1.0.0) - 2015-09-05 \
+            [deployed](https://app.example.com/releases/1.0.0)\n"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_prefer_explicit_author_url_in_remote_template() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[0].author_url = Some("https://git.example.org/~oknozor".to_string());
+        release.commits[1].author_url = Some("https://git.example.org/~paul".to_string());
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".to_string()),
+                Some("cocogitto".to_string()),
+                Some("cocogitto".to_string()),
+            ),
+            kind: TemplateKind::Remote,
+        })?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.contains(
+            "fix parser implementation - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://git.example.org/~oknozor)\n"
+        ));
+        assert!(changelog.contains(
+            "awesome feature - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [Paul Delafosse](https://git.example.org/~paul)\n"
+        ));
+        assert!(changelog.contains(
+            "implement the changelog generator - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor)\n"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_largest_section_first_when_ordered_by_count() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_type_order(TypeOrder::ByCount);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_keep_the_default_order_of_sections_with_as_many_commits() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits.pop();
+        let mut renderer = Renderer::default().with_type_order(TypeOrder::ByCount);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_aggregated_footers() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        for (commit, content) in release.commits.iter_mut().zip(["#1", "#2", "#1"]) {
+            commit.commit.conventional.footers.push(Footer {
+                token: "See-also".to_string(),
+                content: content.to_string(),
+                ..Default::default()
+            });
+        }
+
+        let mut renderer =
+            Renderer::default().with_aggregated_footers(vec!["See-also".to_string()]);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                #### See-also
+                - #1
+                - #2
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_breaking_change_scopes() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        for (commit, content) in release
+            .commits
+            .iter_mut()
+            .zip(["the parser api changed", "the feature api changed"])
+        {
+            commit.commit.conventional.is_breaking_change = true;
+            commit.commit.conventional.footers.push(Footer {
+                token: "BREAKING CHANGE".to_string(),
+                content: content.to_string(),
+                ..Default::default()
+            });
+        }
+
+        let mut renderer = Renderer::default()
+            .with_aggregated_footers(vec!["BREAKING CHANGE".to_string()])
+            .with_breaking_change_scopes(true);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### ⚠ BREAKING CHANGES
+                - **(parser)** fix parser implementation
+                  the parser api changed
+                - awesome feature
+                  the feature api changed
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                #### BREAKING CHANGE
+                - **(parser)** the parser api changed
+                - the feature api changed
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_placeholder_under_empty_sections() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default()
+            .with_always_show_types(vec![CommitType::Documentation, CommitType::Feature])
+            .with_empty_section_placeholder(Some("- _No changes._".to_string()));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Documentation
+                - _No changes._
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_footers_as_definition_list() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits.truncate(1);
+        release.commits[0].commit.conventional.footers = vec![
+            Footer {
+                token: "Refs".to_string(),
+                content: "#42".to_string(),
+                ..Default::default()
+            },
+            Footer {
+                token: "Reviewed-by".to_string(),
+                content: "Jane Doe".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::DefaultWithFooters,
+        })?
+        .with_footer_style(FooterStyle::DefinitionList);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                  **Refs**
+                  : #42
+                  **Reviewed-by**
+                  : Jane Doe
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_breaking_changes_section() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        let breaking = &mut release.commits[0].commit.conventional;
+        breaking.is_breaking_change = true;
+        breaking.footers.push(Footer {
+            token: "BREAKING CHANGE".to_string(),
+            content: "the parser now rejects empty scopes".to_string(),
+            ..Default::default()
+        });
+        release.commits[1].commit.conventional.is_breaking_change = true;
+
+        let mut renderer = Renderer::default();
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### ⚠ BREAKING CHANGES
+                - **(parser)** fix parser implementation
+                  the parser now rejects empty scopes
+                - awesome feature
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_listed_sections_first() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[1].commit.conventional.commit_type = CommitType::Documentation;
+        let mut renderer = Renderer::default()
+            .with_section_order(vec!["Features".to_string(), "Bug Fixes".to_string()]);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Documentation
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_plain_version_header() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_header_style(HeaderStyle::Plain);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_milestone_link_in_remote_mode() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[0].commit.conventional.footers.push(Footer {
+            token: "Milestone".to_string(),
+            content: "v2-roadmap".to_string(),
+            ..Default::default()
+        });
+
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".into()),
+                Some("cocogitto".into()),
+                Some("cocogitto".into()),
+            ),
+            kind: TemplateKind::Remote,
+        })?
+        .with_milestone_token(Some("Milestone".to_string()));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## [1.0.0](https://github.com/cocogitto/cocogitto/compare/0.1.0..1.0.0) - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor) - [v2-roadmap](https://github.com/cocogitto/cocogitto/milestones/v2-roadmap)
+                #### Features
+                - **(parser)** implement the changelog generator - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor)
+                - awesome feature - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_author_handle() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_author_style(AuthorStyle::Handle);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_author_name() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_author_style(AuthorStyle::Name);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - Paul Delafosse
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - James Delleck
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_author_name_and_handle() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".into()),
+                Some("cocogitto".into()),
+                Some("cocogitto".into()),
+            ),
+            kind: TemplateKind::Remote,
+        })?
+        .with_author_style(AuthorStyle::NameAndHandle);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## [1.0.0](https://github.com/cocogitto/cocogitto/compare/0.1.0..1.0.0) - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - Paul Delafosse ([@oknozor](https://github.com/oknozor))
+                #### Features
+                - **(parser)** implement the changelog generator - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - James Delleck ([@oknozor](https://github.com/oknozor))
+                - awesome feature - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_scope_grouped_template() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::ScopeGrouped,
+        })?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                ### parser
+                #### Bug Fixes
+                - fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - implement the changelog generator - (17f7e23) - *oknozor*
+                ### Other
+                #### Features
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_flat_template_with_type_abbreviations() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::Flat,
+        })?
+        .with_type_abbreviations(HashMap::from([
+            (CommitType::Feature, "F".to_string()),
+            (CommitType::BugFix, "B".to_string()),
+        ]));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                B parser: fix parser implementation
+                F awesome feature
+                F parser: implement the changelog generator
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_scoped_commits_before_unscoped_ones() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[0].commit.conventional.commit_type = CommitType::Feature;
+        release.commits[0].commit.conventional.scope = None;
+        release.commits[0].commit.conventional.summary = "unscoped feature".to_string();
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::Flat,
+        })?
+        .with_type_abbreviations(HashMap::from([(CommitType::Feature, "F".to_string())]))
+        .with_scoped_commits_first(true);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                F parser: implement the changelog generator
+                F unscoped feature
+                F awesome feature
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_wrap_long_commit_lines_with_hanging_indent() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits.truncate(1);
+        release.commits[0].commit.conventional.summary =
+            "fix the [parser implementation](https://example.com/parser) for `long summaries`"
+                .to_string();
+        let mut renderer = Renderer::default().with_wrap_width(Some(30));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix the
+                  [parser implementation](https://example.com/parser)
+                  for `long summaries` -
+                  (17f7e23) - *oknozor*
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_highlighted_commits_at_the_top_of_the_release() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        for (commit, content) in release
+            .commits
+            .iter_mut()
+            .zip(["true", "", "A brand new feature"])
+        {
+            if !content.is_empty() {
+                commit.commit.conventional.footers.push(Footer {
+                    token: "Highlight".to_string(),
+                    content: content.to_string(),
+                    ..Default::default()
+                });
+            }
+        }
+        let mut renderer = Renderer::default();
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### ✨ Highlights
+                - fix parser implementation
+                - A brand new feature
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_collapse_internal_sections_after_user_facing_ones() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[0].commit.conventional.commit_type = CommitType::Chore;
+        let mut renderer = Renderer::default()
+            .with_internal_types(vec![CommitType::Chore, CommitType::Ci])
+            .with_always_show_types(vec![CommitType::Ci]);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                <details><summary>Internal changes</summary>
+
+                #### Continuous Integration
+                #### Miscellaneous Chores
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+
+                </details>
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_substitute_translated_summaries() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_summary_translations(HashMap::from([(
+            "awesome feature".to_string(),
+            "fonctionnalité géniale".to_string(),
+        )]));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - fonctionnalité géniale - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_trailing_scope() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_scope_format(ScopeFormat::Trailing);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - fix parser implementation (parser) - (17f7e23) - *oknozor*
+                #### Features
+                - implement the changelog generator (parser) - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_first_release_from_initial_version_label() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.from =
+            OidOf::FirstCommit(Oid::from_str("2c6a2dd1ca5a6c9e5dd8c01ab2b8e21bd4fd8542")?);
+        release.commits.truncate(1);
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".into()),
+                Some("cocogitto".into()),
+                Some("cocogitto".into()),
+            ),
+            kind: TemplateKind::Remote,
+        })?
+        .with_initial_version_label(Some("v0.0.0".to_string()));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## [1.0.0](https://github.com/cocogitto/cocogitto/compare/v0.0.0..1.0.0) - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor)
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_compare_the_oldest_release_against_the_baseline() -> Result<()> {
+        // Arrange
+        let mut previous = Release::fixture();
+        previous.commits.truncate(1);
+        previous.version = OidOf::Tag(Tag::from_str("0.1.0", None, None)?);
+        previous.from =
+            OidOf::FirstCommit(Oid::from_str("2c6a2dd1ca5a6c9e5dd8c01ab2b8e21bd4fd8542")?);
+        let mut release = Release::fixture();
+        release.commits.truncate(1);
+        release.from = previous.version.clone();
+        release.previous = Some(Box::new(previous));
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".into()),
+                Some("cocogitto".into()),
+                Some("cocogitto".into()),
+            ),
+            kind: TemplateKind::Remote,
+        })?
+        .with_baseline(Some("legacy-2.x".to_string()))
+        .with_initial_version_label(Some("v0.0.0".to_string()));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## [1.0.0](https://github.com/cocogitto/cocogitto/compare/0.1.0..1.0.0) - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor)
+
+                - - -
+
+                ## [0.1.0](https://github.com/cocogitto/cocogitto/compare/legacy-2.x..0.1.0) - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor)
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_compare_only_the_oldest_typed_release_against_the_baseline() -> Result<()> {
+        // Arrange
+        let mut previous = Release::fixture();
+        previous.commits.truncate(1);
+        previous.version = OidOf::Tag(Tag::from_str("0.1.0", None, None)?);
+        previous.from =
+            OidOf::FirstCommit(Oid::from_str("2c6a2dd1ca5a6c9e5dd8c01ab2b8e21bd4fd8542")?);
+        let mut release = Release::fixture();
+        release.commits.truncate(1);
+        release.from = previous.version.clone();
+        release.previous = Some(Box::new(previous));
+        let mut renderer = github_remote_renderer()?.with_baseline(Some("legacy-2.x".to_string()));
+
+        // Act
+        let documents = renderer.render_by_type(release)?;
+
+        // Assert
+        assert_eq!(
+            documents[&CommitType::BugFix],
+            indoc! {
+                "## [1.0.0](https://github.com/cocogitto/cocogitto/compare/0.1.0..1.0.0) - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor)
+
+                - - -
+
+                ## [0.1.0](https://github.com/cocogitto/cocogitto/compare/legacy-2.x..0.1.0) - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor)
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_link_author_without_username_to_email() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits.remove(0);
+        release.commits.pop();
+        release.commits[0].author_email = Some("paul.delafosse@protonmail.com".to_string());
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".into()),
+                Some("cocogitto".into()),
+                Some("cocogitto".into()),
+            ),
+            kind: TemplateKind::Remote,
+        })?
+        .with_author_link_fallback(AuthorLinkFallback::Mailto);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## [1.0.0](https://github.com/cocogitto/cocogitto/compare/0.1.0..1.0.0) - 2015-09-05
+                #### Features
+                - awesome feature - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [Paul Delafosse](mailto:paul.delafosse@protonmail.com)
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_gitlab_release_payload() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::GitlabReleasePayload,
+        })?;
+
+        // Act
+        let payload = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            payload,
+            indoc! {
+                r#"{
+                  "version": "1.0.0",
+                  "date": "2015-09-05",
+                  "categories": [
+                    {
+                      "category": "added",
+                      "entries": [
+                        {
+                          "title": "awesome feature",
+                          "sha": "17f7e23081db15e9318aeb37529b1d473cf41cbe",
+                          "author": "Paul Delafosse",
+                          "username": null,
+                          "scope": null,
+                          "breaking_change": false
+                        },
+                        {
+                          "title": "implement the changelog generator",
+                          "sha": "17f7e23081db15e9318aeb37529b1d473cf41cbe",
+                          "author": "James Delleck",
+                          "username": "oknozor",
+                          "scope": "parser",
+                          "breaking_change": false
+                        }
+                      ]
+                    },
+                    {
+                      "category": "fixed",
+                      "entries": [
+                        {
+                          "title": "fix parser implementation",
+                          "sha": "17f7e23081db15e9318aeb37529b1d473cf41cbe",
+                          "author": "Paul Delafosse",
+                          "username": "oknozor",
+                          "scope": "parser",
+                          "breaking_change": false
+                        }
+                      ]
+                    }
+                  ]
+                }
+                "#
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_leading_hash() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_hash_position(HashPosition::Leading);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - (17f7e23) - **(parser)** fix parser implementation - *oknozor*
+                #### Features
+                - (17f7e23) - **(parser)** implement the changelog generator - *oknozor*
+                - (17f7e23) - awesome feature - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_trailing_hash() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_hash_position(HashPosition::Trailing);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_hidden_hash() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_hash_position(HashPosition::Hidden);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - *oknozor*
+                - awesome feature - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_one_document_per_commit_type() -> Result<()> {
+        // Arrange
+        let mut previous = Release::fixture();
+        previous.commits.truncate(2);
+        previous.version = OidOf::Tag(Tag::from_str("0.1.0", None, None)?);
+        let mut release = Release::fixture();
+        release.commits.remove(1);
+        release.previous = Some(Box::new(previous));
+        let mut renderer = Renderer::default();
+
+        // Act
+        let documents = renderer.render_by_type(release)?;
+
+        // Assert
+        assert_eq!(documents.len(), 2);
+        assert_eq!(
+            documents[&CommitType::Feature],
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+
+                - - -
+
+                ## 0.1.0 - 2015-09-05
+                #### Features
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_one_remote_document_per_commit_type() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits.truncate(1);
+        let mut renderer = github_remote_renderer()?;
+
+        // Act
+        let documents = renderer.render_by_type(release)?;
+
+        // Assert
+        assert_eq!(
+            documents[&CommitType::BugFix],
+            indoc! {
+                "## [1.0.0](https://github.com/cocogitto/cocogitto/compare/0.1.0..1.0.0) - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor)
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_signature_badges_from_trusted_keys() -> Result<()> {
+        // Arrange
+        let trusted_key = "19849D954E666101148057DE1A931C2A384BB3CD".to_string();
+        let mut release = Release::fixture();
+        release.commits[0].commit_signature = Some(CommitSignature {
+            key: Some(trusted_key.clone()),
+        });
+        release.commits[1].commit_signature = Some(CommitSignature {
+            key: Some("5578937498775A86D42A508376067339C9165C77".to_string()),
+        });
+        let mut renderer = Renderer::default().with_trusted_keys(HashSet::from([trusted_key]));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor* ✔
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse 🔒
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_commit_labels_as_badges() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[0].commit.oid = "1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d".to_string();
+        release.commits[1].commit.conventional.summary = "awesome feature (#42)".to_string();
+        let mut renderer = Renderer::default().with_commit_labels(HashMap::from([
+            (
+                release.commits[0].commit.oid.clone(),
+                vec!["bug".to_string(), "parser".to_string()],
+            ),
+            ("42".to_string(), vec!["enhancement".to_string()]),
+        ]));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (1c2d3e4) - *oknozor* `bug` `parser`
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature (#42) - (17f7e23) - Paul Delafosse `enhancement`
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_terminal_preview() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default();
+
+        // Act
+        let preview = renderer.render_terminal(release)?;
+
+        // Assert
+        assert_eq!(
+            strip_colors(&preview),
+            indoc! {
+                "1.0.0 - 2015-09-05
+                Bug Fixes
+                - (parser) fix parser implementation - 17f7e23 - oknozor
+                Features
+                - (parser) implement the changelog generator - 17f7e23 - oknozor
+                - awesome feature - 17f7e23 - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_terminal_preview_of_unreleased_breaking_changes() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.version = OidOf::Head(Oid::from_str("9bb5facac5724bc81385fdd740fedbb49056da00")?);
+        release.commits[1].commit.conventional.is_breaking_change = true;
+        let mut renderer = Renderer::default()
+            .with_unreleased_label("Upcoming".to_string())
+            .with_internal_types(vec![CommitType::BugFix]);
+
+        // Act
+        let preview = renderer.render_terminal(release)?;
+
+        // Assert
+        assert_eq!(
+            strip_colors(&preview),
+            indoc! {
+                "Upcoming
+                ⚠ BREAKING CHANGES
+                - awesome feature
+                Features
+                - (parser) implement the changelog generator - 17f7e23 - oknozor
+                - awesome feature - 17f7e23 - Paul Delafosse
+                Bug Fixes
+                - (parser) fix parser implementation - 17f7e23 - oknozor
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    fn strip_colors(text: &str) -> String {
+        let mut stripped = String::new();
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c == '\u{1b}' {
+                chars.by_ref().find(|c| *c == 'm');
+            } else {
+                stripped.push(c);
+            }
+        }
+
+        stripped
+    }
+
+    #[test]
+    fn should_render_unreleased_compare_link_to_configured_ref() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.version = OidOf::Head(Oid::from_str("9bb5facac5724bc81385fdd740fedbb49056da00")?);
+        release.from = OidOf::Other(Oid::from_str("fae3a288a1bc69b14f85a1d5fe57cee1964acd60")?);
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".into()),
+                Some("cocogitto".into()),
+                Some("cocogitto".into()),
+            ),
+            kind: TemplateKind::Remote,
+        })?
+        .with_unreleased_compare_ref("main".to_string());
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.starts_with(
+            "## Unreleased ([fae3a28..9bb5fac](https://github.com/cocogitto/cocogitto/compare/fae3a28..main))"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_link_issue_references_to_their_tracker() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[0].commit.conventional.summary = "fix parser implementation (#42)".into();
+        release.commits[1].commit.conventional.summary = "awesome feature, see JIRA-9".into();
+        let mut renderer = Renderer::default().with_issue_trackers(HashMap::from([
+            (
+                "#".to_string(),
+                "https://github.com/cocogitto/cocogitto/issues/{id}".to_string(),
+            ),
+            (
+                "JIRA-".to_string(),
+                "https://jira.example.com/browse/JIRA-{id}".to_string(),
+            ),
+        ]));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation ([#42](https://github.com/cocogitto/cocogitto/issues/42)) - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature, see [JIRA-9](https://jira.example.com/browse/JIRA-9) - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_group_scopeless_commits_by_named_bucket_fallback() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_grouping(Grouping {
+            key: GroupBy::Scope,
+            fallback: GroupFallback::Bucket("Other".to_string()),
+            ..Default::default()
+        });
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Other
+                - awesome feature - (17f7e23) - Paul Delafosse
+                #### Parser
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_scope_headings_with_commit_counts() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default()
+            .with_scope_counts(true)
+            .with_grouping(Grouping {
+                key: GroupBy::Scope,
+                fallback: GroupFallback::Bucket("Other".to_string()),
+                ..Default::default()
+            });
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Other
+                - awesome feature - (17f7e23) - Paul Delafosse
+                #### Parser (2)
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_group_scopeless_commits_by_type_fallback() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_grouping(Grouping {
+            key: GroupBy::Scope,
+            fallback: GroupFallback::Type,
+            ..Default::default()
+        });
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Features
+                - awesome feature - (17f7e23) - Paul Delafosse
+                #### Parser
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_group_scopeless_commits_by_omission_fallback() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_grouping(Grouping {
+            key: GroupBy::Scope,
+            fallback: GroupFallback::Omit,
+            ..Default::default()
+        });
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Parser
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_group_count_digits_with_configured_separator() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        let template = release.commits.remove(0);
+        release.commits = (0..1234)
+            .map(|_| ChangelogCommit {
+                author_username: template.author_username,
+                author_email: None,
+                author_url: None,
+                commit: template.commit.clone(),
+                commit_signature: None,
+                reverted: false,
+                branch: None,
+            })
+            .collect();
+        let mut renderer = Renderer::default().with_thousands_separator(Some(".".to_string()));
+        renderer.tera.add_raw_template(
+            TemplateKind::Default.name(),
+            "{% for name, count in contributors %}{{ name }}: {{ count | format_count(separator=thousands_separator) }}{% endfor %}",
+        )?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(changelog, "Paul Delafosse: 1.234");
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_release_compare_range_per_commit_in_flat_template() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits.truncate(1);
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".to_string()),
+                Some("cocogitto".to_string()),
+                Some("cocogitto".to_string()),
+            ),
+            kind: TemplateKind::Flat,
+        })?
+        .with_type_abbreviations(HashMap::from([(CommitType::BugFix, "B".to_string())]))
+        .with_compare_links(true);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                B parser: fix parser implementation [range](https://github.com/cocogitto/cocogitto/compare/0.1.0..1.0.0)
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_drop_duplicated_release_with_fewer_commits() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        let mut duplicate = Release::fixture();
+        duplicate.commits.truncate(1);
+        release.previous = Some(Box::new(duplicate));
+        let mut renderer = Renderer::default().with_dedup_releases(true);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_activity_bars_proportional_to_release_sizes() -> Result<()> {
+        // Arrange
+        let release_at = |version: &str, from: &str, commits: usize| -> Result<Release> {
+            let mut release = Release::fixture();
+            release.version = OidOf::Tag(Tag::from_str(version, None, None)?);
+            release.from = OidOf::Tag(Tag::from_str(from, None, None)?);
+            release.commits.truncate(commits);
+            Ok(release)
+        };
+
+        let first = release_at("1.0.0", "0.1.0", 1)?;
+        let mut second = release_at("1.1.0", "1.0.0", 2)?;
+        second.previous = Some(Box::new(first));
+        let mut release = release_at("1.2.0", "1.1.0", 3)?;
+        release.previous = Some(Box::new(second));
+
+        let mut renderer = Renderer::default().with_activity_bar(true);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        let headers: Vec<&str> = changelog
+            .lines()
+            .filter(|line| line.starts_with("## "))
+            .collect();
+
+        assert_eq!(
+            headers,
+            vec![
+                "## 1.2.0 - 2015-09-05 █",
+                "## 1.1.0 - 2015-09-05 ▆",
+                "## 1.0.0 - 2015-09-05 ▃",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_fold_pre_releases_into_the_next_release() -> Result<()> {
+        // Arrange
+        let release_at = |version: &str, from: &str, keep: usize| -> Result<Release> {
+            let mut release = Release::fixture();
+            release.version = OidOf::Tag(Tag::from_str(version, None, None)?);
+            release.from = OidOf::Tag(Tag::from_str(from, None, None)?);
+            let kept = release.commits.remove(keep);
+            release.commits = vec![kept];
+            Ok(release)
+        };
+
+        let mut stable = release_at("1.0.0", "0.1.0", 0)?;
+        stable.commits[0].commit.conventional.summary = "initial parser".to_string();
+        let mut rc_1 = release_at("1.1.0-rc.1", "1.0.0", 0)?;
+        rc_1.previous = Some(Box::new(stable));
+        let mut rc_2 = release_at("1.1.0-rc.2", "1.1.0-rc.1", 1)?;
+        rc_2.previous = Some(Box::new(rc_1));
+        let mut release = release_at("1.1.0", "1.1.0-rc.2", 2)?;
+        release.previous = Some(Box::new(rc_2));
+
+        let mut renderer = Renderer::default().with_fold_pre_releases(true);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.1.0 - 2015-09-05
+                <details><summary>Pre-releases</summary>
+
+                - 1.1.0-rc.2 (1 commit)
+                - 1.1.0-rc.1 (1 commit)
+
+                </details>
+
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+
+                - - -
+
+                ## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** initial parser - (17f7e23) - *oknozor*
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_only_render_commits_from_allowed_authors() -> Result<()> {
+        // Arrange
+        let mut previous = Release::fixture();
+        previous.version = OidOf::Tag(Tag::from_str("0.1.0", None, None)?);
+        previous.commits.remove(0);
+        previous.commits.pop();
+        let mut release = Release::fixture();
+        release.previous = Some(Box::new(previous));
+        let mut renderer =
+            Renderer::default().with_author_allowlist(HashSet::from(["oknozor".to_string()]));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_setext_version_header() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_heading_syntax(HeadingSyntax::Setext);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "1.0.0 - 2015-09-05
+                ------------------
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_only_render_commits_with_required_label() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[0].commit.conventional.footers = vec![Footer {
+            token: "Labels".to_string(),
+            content: "changelog, public".to_string(),
+            ..Default::default()
+        }];
+        release.commits[1].commit.conventional.footers = vec![Footer {
+            token: "Labels".to_string(),
+            content: "changelog".to_string(),
+            ..Default::default()
+        }];
+        let mut renderer =
+            Renderer::default().with_required_label("Labels".to_string(), "public".to_string());
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_compare_link_with_configured_separator() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".to_string()),
+                Some("cocogitto".to_string()),
+                Some("cocogitto".to_string()),
+            )
+            .map(|context| context.with_compare_separator(Some("...".to_string()))),
+            kind: TemplateKind::Remote,
+        })?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.starts_with(
+            "## [1.0.0](https://github.com/cocogitto/cocogitto/compare/0.1.0...1.0.0) - 2015-09-05\n"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_each_contributor_once_across_releases() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        let mut previous = Release::fixture();
+        previous.commits[1].commit.conventional.footers = vec![Footer {
+            token: "Co-authored-by".to_string(),
+            content: "Jane Doe <jane@doe.org>".to_string(),
+            ..Default::default()
+        }];
+        release.previous = Some(Box::new(previous));
+        let renderer = Renderer::try_new(Template {
+            remote_context: RemoteContext::try_new(
+                Some("github.com".to_string()),
+                Some("cocogitto".to_string()),
+                Some("cocogitto".to_string()),
+            ),
+            kind: TemplateKind::Remote,
+        })?
+        .with_contributor_counts(true);
+
+        // Act
+        let contributors = renderer.render_contributors(release);
+
+        // Assert
+        assert_eq!(
+            contributors,
+            indoc! {
+                "- James Delleck ([@oknozor](https://github.com/oknozor)) - 2 commits
+                - Jane Doe - 1 commits
+                - Paul Delafosse ([@oknozor](https://github.com/oknozor)) - 4 commits
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_deduplicated_contributors_trailer() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[1].commit.conventional.footers = vec![
+            Footer {
+                token: "Co-authored-by".to_string(),
+                content: "Jane Doe <jane@doe.org>".to_string(),
+                ..Default::default()
+            },
+            Footer {
+                token: "Co-authored-by".to_string(),
+                content: "James Delleck <james@delleck.org>".to_string(),
+                ..Default::default()
+            },
+        ];
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::DefaultWithContributors,
+        })?;
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.ends_with(indoc! {
+            "#### Contributors
+            - James Delleck (@oknozor)
+            - Jane Doe
+            - Paul Delafosse (@oknozor)
+            "
+        }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_commit_dates_with_the_configured_format() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[1].commit.date = chrono::NaiveDate::from_ymd_opt(2015, 9, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let mut renderer = Renderer::try_new(Template {
+            remote_context: None,
+            kind: TemplateKind::DefaultWithDates,
+        })?
+        .with_date_format("%d/%m/%Y".to_string());
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor* - 05/09/2015
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor* - 05/09/2015
+                - awesome feature - (17f7e23) - Paul Delafosse - 01/09/2015
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_group_authors_below_the_commit_threshold_under_others() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_grouping(Grouping {
+            key: GroupBy::Author,
+            min_commits_for_own_section: 2,
+            ..Default::default()
+        });
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Oknozor
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                #### Others
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_inline_scopeless_commits_without_heading() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_grouping(Grouping {
+            key: GroupBy::Scope,
+            fallback: GroupFallback::Inline,
+            ..Default::default()
+        });
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                - awesome feature - (17f7e23) - Paul Delafosse
+                #### Parser
+                - **(parser)** fix parser implementation - (17f7e23) - *oknozor*
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_summaries_as_is() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[0].commit.conventional.summary = "Fix parser implementation.".to_string();
+        release.commits[1].commit.conventional.summary = "awesome feature!..".to_string();
+        let mut renderer = Renderer::default().with_summary_style(SummaryStyle::AsIs);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** Fix parser implementation. - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature!.. - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_summaries_sentence_case() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[0].commit.conventional.summary = "Fix parser implementation.".to_string();
+        release.commits[1].commit.conventional.summary = "awesome feature!..".to_string();
+        let mut renderer = Renderer::default().with_summary_style(SummaryStyle::SentenceCase);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** Fix parser implementation - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** Implement the changelog generator - (17f7e23) - *oknozor*
+                - Awesome feature! - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_summaries_sentence_case_with_period() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[0].commit.conventional.summary = "Fix parser implementation.".to_string();
+        release.commits[1].commit.conventional.summary = "awesome feature!..".to_string();
+        let mut renderer =
+            Renderer::default().with_summary_style(SummaryStyle::SentenceCaseWithPeriod);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Bug Fixes
+                - **(parser)** Fix parser implementation. - (17f7e23) - *oknozor*
+                #### Features
+                - **(parser)** Implement the changelog generator. - (17f7e23) - *oknozor*
+                - Awesome feature! - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_table_of_contents_version_only() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        let mut previous = Release::fixture();
+        previous.version = OidOf::Tag(Tag::from_str("0.1.0", None, None)?);
+        release.previous = Some(Box::new(previous));
+        let mut renderer = Renderer::default().with_table_of_contents(2);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.starts_with(indoc! {
+                "- [1.0.0 - 2015-09-05](#100---2015-09-05)
+                - [0.1.0 - 2015-09-05](#010---2015-09-05)
+
+                ## 1.0.0 - 2015-09-05
+                "
+        }));
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_table_of_contents_with_sections() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        let mut previous = Release::fixture();
+        previous.version = OidOf::Tag(Tag::from_str("0.1.0", None, None)?);
+        release.previous = Some(Box::new(previous));
+        let mut renderer = Renderer::default().with_table_of_contents(4);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.starts_with(indoc! {
+                "- [1.0.0 - 2015-09-05](#100---2015-09-05)
+                  - [Bug Fixes](#bug-fixes)
+                  - [Features](#features)
+                - [0.1.0 - 2015-09-05](#010---2015-09-05)
+                  - [Bug Fixes](#bug-fixes-1)
+                  - [Features](#features-1)
+
+                ## 1.0.0 - 2015-09-05
+                "
+        }));
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_release_date_in_configured_timezone() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_timezone(Some(chrono_tz::Asia::Tokyo));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.starts_with("## 1.0.0 - 2015-09-06\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_release_chain_in_pages() -> Result<()> {
+        // Arrange
+        let mut release: Option<Release> = None;
+        for (from, version) in ["0.1.0", "1.0.0", "2.0.0", "3.0.0", "4.0.0", "5.0.0"]
+            .windows(2)
+            .map(|versions| (versions[0], versions[1]))
+        {
+            let mut next = Release::fixture();
+            next.version = OidOf::Tag(Tag::from_str(version, None, None)?);
+            next.from = OidOf::Tag(Tag::from_str(from, None, None)?);
+            next.previous = release.map(Box::new);
+            release = Some(next);
+        }
+        let mut renderer = github_remote_renderer()?;
+
+        // Act
+        let pages = renderer.render_paginated(release.expect("a release chain"), 2)?;
+
+        // Assert
+        let headers: Vec<Vec<&str>> = pages
+            .iter()
+            .map(|page| {
+                page.lines()
+                    .filter(|line| line.starts_with("## "))
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(
+            headers,
+            vec![
+                vec![
+                    "## [5.0.0](https://github.com/cocogitto/cocogitto/compare/4.0.0..5.0.0) - 2015-09-05",
+                    "## [4.0.0](https://github.com/cocogitto/cocogitto/compare/3.0.0..4.0.0) - 2015-09-05",
+                ],
+                vec![
+                    "## [3.0.0](https://github.com/cocogitto/cocogitto/compare/2.0.0..3.0.0) - 2015-09-05",
+                    "## [2.0.0](https://github.com/cocogitto/cocogitto/compare/1.0.0..2.0.0) - 2015-09-05",
+                ],
+                vec![
+                    "## [1.0.0](https://github.com/cocogitto/cocogitto/compare/0.1.0..1.0.0) - 2015-09-05",
+                ],
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_commit_line_suffix() -> Result<()> {
+        // Arrange
+        let mut release = Release::fixture();
+        release.commits[0].commit.conventional.summary =
+            "fix parser implementation (#42)".to_string();
+        let mut renderer = Renderer::default()
+            .with_commit_line_suffix(Some(" by {author} on {date} ({hash}) {pr}".to_string()));
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.contains(
+            "- **(parser)** fix parser implementation (#42) by oknozor on 2015-09-05 (17f7e23) #42\n"
+        ));
+        assert!(
+            changelog.contains("- awesome feature by Paul Delafosse on 2015-09-05 (17f7e23) \n")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_release_date_with_configured_format() -> Result<()> {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_release_date_format("%d %B %Y".to_string());
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert!(changelog.starts_with("## 1.0.0 - 05 September 2015\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_invalid_release_date_format() {
+        // Arrange
+        let release = Release::fixture();
+        let mut renderer = Renderer::default().with_release_date_format("%Y-%Q".to_string());
+
+        // Act
+        let result = renderer.render(release);
+
+        // Assert
+        let error = result.expect_err("invalid date format should not render");
+        let source = std::error::Error::source(&error)
+            .and_then(|source| source.downcast_ref::<ChangelogError>());
+        assert!(matches!(
+            source,
+            Some(ChangelogError::InvalidDateFormat(format)) if format == "%Y-%Q"
+        ));
     }
 }
@@ -1,3 +1,4 @@
+use conventional_commit_parser::commit::CommitType;
 use serde::ser::SerializeStruct;
 use serde::{Serialize, Serializer};
 
@@ -6,6 +7,15 @@ use crate::git::oid::OidOf;
 use crate::git::tag::Tag;
 use crate::COMMITS_METADATA;
 
+/// Serialize a package path with forward slashes so that it can be used as a link target
+/// whatever the separator of the host OS.
+pub(crate) fn serialize_url_path<S>(path: &&str, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&path.replace('\\', "/"))
+}
+
 impl Serialize for Tag {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -20,7 +30,7 @@ impl Serialize for ChangelogCommit<'_> {
     where
         S: Serializer,
     {
-        let mut commit = serializer.serialize_struct("Commit", 10)?;
+        let mut commit = serializer.serialize_struct("Commit", 14)?;
 
         let footers = &self
             .commit
@@ -30,15 +40,13 @@ impl Serialize for ChangelogCommit<'_> {
             .map(ChangelogFooter::from)
             .collect::<Vec<ChangelogFooter>>();
 
-        let commit_type = &COMMITS_METADATA
-            .iter()
-            .find(|(commit_type, _config)| *commit_type == &self.commit.conventional.commit_type)
-            .map(|meta| meta.1.changelog_title.clone())
-            .unwrap_or_else(|| self.commit.conventional.commit_type.to_string());
+        let commit_type = &changelog_title(&self.commit.conventional.commit_type);
 
         commit.serialize_field("id", &self.commit.oid)?;
         commit.serialize_field("author", &self.author_username)?;
         commit.serialize_field("signature", &self.commit.author)?;
+        commit.serialize_field("email", &self.author_email)?;
+        commit.serialize_field("author_url", &self.author_url)?;
         commit.serialize_field("type", commit_type)?;
         commit.serialize_field("date", &self.commit.date)?;
         commit.serialize_field("scope", &self.commit.conventional.scope)?;
@@ -48,17 +56,33 @@ impl Serialize for ChangelogCommit<'_> {
             "breaking_change",
             &self.commit.conventional.is_breaking_change,
         )?;
+        commit.serialize_field("reverted", &self.reverted)?;
+        commit.serialize_field("branch", &self.branch)?;
         commit.serialize_field("footer", footers)?;
         commit.end()
     }
 }
 
+/// The changelog section title of the given commit type.
+pub(crate) fn changelog_title(commit_type: &CommitType) -> String {
+    COMMITS_METADATA
+        .get(commit_type)
+        .map(|config| config.changelog_title.clone())
+        .unwrap_or_else(|| commit_type.to_string())
+}
+
 impl Serialize for OidOf {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut oidof = serializer.serialize_struct("OidOf", 1)?;
+        let mut oidof = serializer.serialize_struct("OidOf", 4)?;
+        let kind = match self {
+            OidOf::Tag(_) => "tag",
+            OidOf::FirstCommit(_) => "first_commit",
+            OidOf::Head(_) | OidOf::Other(_) => "commit",
+        };
+        oidof.serialize_field("type", kind)?;
         match self {
             OidOf::Tag(tag) => {
                 oidof.serialize_field("tag", &tag.to_string())?;
@@ -70,6 +94,7 @@ impl Serialize for OidOf {
                 oidof.serialize_field("id", &oid.to_string())?
             }
         };
+        oidof.serialize_field("display", &self.to_string())?;
         oidof.end()
     }
 }
@@ -80,9 +105,11 @@ mod test {
     use conventional_commit_parser::commit::{CommitType, ConventionalCommit, Footer};
     use git2::Oid;
     use speculoos::prelude::*;
+    use tera::Value;
 
-    use crate::conventional::changelog::release::ChangelogCommit;
+    use crate::conventional::changelog::release::{ChangelogCommit, Release};
     use crate::conventional::commit::Commit;
+    use crate::git::oid::OidOf;
     use crate::git::tag::Tag;
 
     #[test]
@@ -101,6 +128,11 @@ mod test {
     fn should_serialize_commit() {
         let commit = ChangelogCommit {
             author_username: Some("Jm Doudou"),
+            author_email: None,
+            author_url: None,
+            commit_signature: None,
+            reverted: false,
+            branch: None,
             commit: Commit {
                 oid: "1234567890".to_string(),
                 conventional: ConventionalCommit {
@@ -124,4 +156,20 @@ mod test {
 
         assert_that!(result).is_ok();
     }
+
+    #[test]
+    fn should_tag_refs_of_a_first_release_with_their_type() -> anyhow::Result<()> {
+        let mut release = Release::fixture();
+        let first_commit = Oid::from_str("17f7e23081db15e9318aeb37529b1d473cf41cbe")?;
+        release.from = OidOf::FirstCommit(first_commit);
+
+        let result = tera::to_value(&release)?;
+
+        assert_that!(result["version"]["type"]).is_equal_to(Value::from("tag"));
+        assert_that!(result["version"]["display"]).is_equal_to(Value::from("1.0.0"));
+        assert_that!(result["from"]["type"]).is_equal_to(Value::from("first_commit"));
+        assert_that!(result["from"]["id"]).is_equal_to(Value::from(first_commit.to_string()));
+        assert_that!(result["from"]["display"]).is_equal_to(Value::from(first_commit.to_string()));
+        Ok(())
+    }
 }
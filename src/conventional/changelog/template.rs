@@ -2,17 +2,31 @@ use crate::conventional::changelog::error::ChangelogError;
 
 use serde::Serialize;
 
-use crate::git::oid::OidOf;
+use crate::git::oid::{OidOf, TagDisplayContext};
+use crate::settings::HashPosition;
 use std::io;
 use std::path::PathBuf;
 use tera::Context;
 
 const DEFAULT_TEMPLATE: &[u8] = include_bytes!("template/simple");
 const DEFAULT_TEMPLATE_NAME: &str = "default";
+const DEFAULT_WITH_FOOTERS_TEMPLATE: &[u8] = include_bytes!("template/simple_with_footers");
+const DEFAULT_WITH_FOOTERS_TEMPLATE_NAME: &str = "default_with_footers";
+const DEFAULT_WITH_CONTRIBUTORS_TEMPLATE: &[u8] =
+    include_bytes!("template/simple_with_contributors");
+const DEFAULT_WITH_CONTRIBUTORS_TEMPLATE_NAME: &str = "default_with_contributors";
+const DEFAULT_WITH_DATES_TEMPLATE: &[u8] = include_bytes!("template/simple_with_dates");
+const DEFAULT_WITH_DATES_TEMPLATE_NAME: &str = "default_with_dates";
 const REMOTE_TEMPLATE: &[u8] = include_bytes!("template/remote");
 const REMOTE_TEMPLATE_NAME: &str = "remote";
 const FULL_HASH_TEMPLATE: &[u8] = include_bytes!("template/full_hash");
 const FULL_HASH_TEMPLATE_NAME: &str = "full_hash";
+const FLAT_TEMPLATE: &[u8] = include_bytes!("template/flat");
+const FLAT_TEMPLATE_NAME: &str = "flat";
+const GITLAB_RELEASE_PAYLOAD_TEMPLATE: &[u8] = include_bytes!("template/gitlab_release_payload");
+const GITLAB_RELEASE_PAYLOAD_TEMPLATE_NAME: &str = "gitlab_release_payload";
+const SCOPE_GROUPED_TEMPLATE: &[u8] = include_bytes!("template/scope_grouped");
+const SCOPE_GROUPED_TEMPLATE_NAME: &str = "scope_grouped";
 
 const PACKAGE_DEFAULT_TEMPLATE: &[u8] = include_bytes!("template/package_simple");
 const PACKAGE_DEFAULT_TEMPLATE_NAME: &str = "package_default";
@@ -27,6 +41,11 @@ const MONOREPO_REMOTE_TEMPLATE: &[u8] = include_bytes!("template/monorepo_remote
 const MONOREPO_REMOTE_TEMPLATE_NAME: &str = "monorepo_remote";
 const MONOREPO_FULL_HASH_TEMPLATE: &[u8] = include_bytes!("template/monorepo_full_hash");
 const MONOREPO_FULL_HASH_TEMPLATE_NAME: &str = "monorepo_full_hash";
+const MONOREPO_COMPACT_TEMPLATE: &[u8] = include_bytes!("template/monorepo_compact");
+const MONOREPO_COMPACT_TEMPLATE_NAME: &str = "monorepo_compact";
+
+/// Prefix forcing a template argument to be read as a path, e.g. `file:remote`
+const CUSTOM_TEMPLATE_PREFIX: &str = "file:";
 
 #[derive(Debug, Default)]
 pub struct Template {
@@ -36,27 +55,76 @@ pub struct Template {
 
 impl Template {
     pub fn from_arg(value: &str, context: Option<RemoteContext>) -> Result<Self, ChangelogError> {
-        let template = TemplateKind::from_arg(value)?;
+        // `gitlab_remote`, `gitea_package_remote`... are the remote templates for another platform
+        let remote_kind = [
+            ("gitlab_", RemoteKind::GitLab),
+            ("gitea_", RemoteKind::Gitea),
+            ("bitbucket_", RemoteKind::Bitbucket),
+        ]
+        .into_iter()
+        .find_map(|(prefix, kind)| {
+            value
+                .strip_prefix(prefix)
+                .filter(|name| {
+                    [
+                        REMOTE_TEMPLATE_NAME,
+                        PACKAGE_REMOTE_TEMPLATE_NAME,
+                        MONOREPO_REMOTE_TEMPLATE_NAME,
+                    ]
+                    .contains(name)
+                })
+                .map(|name| (name, kind))
+        });
+
+        let (template, remote_kind) = match remote_kind {
+            Some((name, kind)) => (name, Some(kind)),
+            None => (value, None),
+        };
+
+        let template = TemplateKind::from_arg(template)?;
+        let context = match remote_kind {
+            Some(kind) => context.map(|context| context.with_kind(kind)),
+            None => context,
+        };
 
         Ok(Template {
             remote_context: context,
             kind: template,
         })
     }
+
+    /// Load a custom Tera template from `path`, it is rendered with the same context as the
+    /// built-in templates.
+    pub fn from_file(
+        path: PathBuf,
+        context: Option<RemoteContext>,
+    ) -> Result<Self, ChangelogError> {
+        Ok(Template {
+            remote_context: context,
+            kind: TemplateKind::from_file(path)?,
+        })
+    }
 }
 
 #[derive(Debug, Default)]
 pub enum TemplateKind {
     #[default]
     Default,
+    DefaultWithFooters,
+    DefaultWithContributors,
+    DefaultWithDates,
     FullHash,
+    Flat,
     Remote,
+    GitlabReleasePayload,
+    ScopeGrouped,
     PackageDefault,
     PackageFullHash,
     PackageRemote,
     MonorepoDefault,
     MonorepoFullHash,
     MonorepoRemote,
+    MonorepoCompact,
     Custom(PathBuf),
 }
 
@@ -65,36 +133,63 @@ impl TemplateKind {
     fn from_arg(value: &str) -> Result<Self, ChangelogError> {
         match value {
             DEFAULT_TEMPLATE_NAME => Ok(TemplateKind::Default),
+            DEFAULT_WITH_FOOTERS_TEMPLATE_NAME => Ok(TemplateKind::DefaultWithFooters),
+            DEFAULT_WITH_CONTRIBUTORS_TEMPLATE_NAME => Ok(TemplateKind::DefaultWithContributors),
+            DEFAULT_WITH_DATES_TEMPLATE_NAME => Ok(TemplateKind::DefaultWithDates),
             REMOTE_TEMPLATE_NAME => Ok(TemplateKind::Remote),
             FULL_HASH_TEMPLATE_NAME => Ok(TemplateKind::FullHash),
+            FLAT_TEMPLATE_NAME => Ok(TemplateKind::Flat),
+            GITLAB_RELEASE_PAYLOAD_TEMPLATE_NAME => Ok(TemplateKind::GitlabReleasePayload),
+            SCOPE_GROUPED_TEMPLATE_NAME => Ok(TemplateKind::ScopeGrouped),
             PACKAGE_DEFAULT_TEMPLATE_NAME => Ok(TemplateKind::PackageDefault),
             PACKAGE_REMOTE_TEMPLATE_NAME => Ok(TemplateKind::PackageRemote),
             PACKAGE_FULL_HASH_TEMPLATE_NAME => Ok(TemplateKind::PackageFullHash),
             MONOREPO_DEFAULT_TEMPLATE_NAME => Ok(TemplateKind::MonorepoDefault),
             MONOREPO_REMOTE_TEMPLATE_NAME => Ok(TemplateKind::MonorepoRemote),
             MONOREPO_FULL_HASH_TEMPLATE_NAME => Ok(TemplateKind::MonorepoFullHash),
-            path => {
-                let path = PathBuf::from(path);
-                if !path.exists() {
-                    return Err(ChangelogError::TemplateNotFound(path));
-                }
-
-                Ok(TemplateKind::Custom(path))
+            MONOREPO_COMPACT_TEMPLATE_NAME => Ok(TemplateKind::MonorepoCompact),
+            value => {
+                let path = value.strip_prefix(CUSTOM_TEMPLATE_PREFIX).unwrap_or(value);
+                Self::from_file(PathBuf::from(path))
             }
         }
     }
 
+    /// Returns a custom template, checking that the file exists and is a valid Tera template
+    fn from_file(path: PathBuf) -> Result<Self, ChangelogError> {
+        if !path.is_file() {
+            return Err(ChangelogError::TemplateNotFound(path));
+        }
+
+        let content = std::fs::read(&path)?;
+        let content = String::from_utf8_lossy(content.as_slice());
+        if let Err(err) = tera::Tera::default().add_raw_template("custom_template", &content) {
+            return Err(ChangelogError::InvalidTemplate(path, err));
+        }
+
+        Ok(TemplateKind::Custom(path))
+    }
+
     pub(crate) fn get(&self) -> Result<Vec<u8>, io::Error> {
         match self {
             TemplateKind::Default => Ok(DEFAULT_TEMPLATE.to_vec()),
+            TemplateKind::DefaultWithFooters => Ok(DEFAULT_WITH_FOOTERS_TEMPLATE.to_vec()),
+            TemplateKind::DefaultWithContributors => {
+                Ok(DEFAULT_WITH_CONTRIBUTORS_TEMPLATE.to_vec())
+            }
+            TemplateKind::DefaultWithDates => Ok(DEFAULT_WITH_DATES_TEMPLATE.to_vec()),
             TemplateKind::Remote => Ok(REMOTE_TEMPLATE.to_vec()),
             TemplateKind::FullHash => Ok(FULL_HASH_TEMPLATE.to_vec()),
+            TemplateKind::Flat => Ok(FLAT_TEMPLATE.to_vec()),
+            TemplateKind::GitlabReleasePayload => Ok(GITLAB_RELEASE_PAYLOAD_TEMPLATE.to_vec()),
+            TemplateKind::ScopeGrouped => Ok(SCOPE_GROUPED_TEMPLATE.to_vec()),
             TemplateKind::PackageDefault => Ok(PACKAGE_DEFAULT_TEMPLATE.to_vec()),
             TemplateKind::PackageRemote => Ok(PACKAGE_REMOTE_TEMPLATE.to_vec()),
             TemplateKind::PackageFullHash => Ok(PACKAGE_FULL_HASH_TEMPLATE.to_vec()),
             TemplateKind::MonorepoDefault => Ok(MONOREPO_DEFAULT_TEMPLATE.to_vec()),
             TemplateKind::MonorepoRemote => Ok(MONOREPO_REMOTE_TEMPLATE.to_vec()),
             TemplateKind::MonorepoFullHash => Ok(MONOREPO_FULL_HASH_TEMPLATE.to_vec()),
+            TemplateKind::MonorepoCompact => Ok(MONOREPO_COMPACT_TEMPLATE.to_vec()),
             TemplateKind::Custom(path) => std::fs::read(path),
         }
     }
@@ -102,25 +197,67 @@ impl TemplateKind {
     pub(crate) const fn name(&self) -> &'static str {
         match self {
             TemplateKind::Default => DEFAULT_TEMPLATE_NAME,
+            TemplateKind::DefaultWithFooters => DEFAULT_WITH_FOOTERS_TEMPLATE_NAME,
+            TemplateKind::DefaultWithContributors => DEFAULT_WITH_CONTRIBUTORS_TEMPLATE_NAME,
+            TemplateKind::DefaultWithDates => DEFAULT_WITH_DATES_TEMPLATE_NAME,
             TemplateKind::Remote => REMOTE_TEMPLATE_NAME,
             TemplateKind::FullHash => FULL_HASH_TEMPLATE_NAME,
+            TemplateKind::Flat => FLAT_TEMPLATE_NAME,
+            TemplateKind::GitlabReleasePayload => GITLAB_RELEASE_PAYLOAD_TEMPLATE_NAME,
+            TemplateKind::ScopeGrouped => SCOPE_GROUPED_TEMPLATE_NAME,
             TemplateKind::PackageDefault => PACKAGE_DEFAULT_TEMPLATE_NAME,
             TemplateKind::PackageRemote => PACKAGE_REMOTE_TEMPLATE_NAME,
             TemplateKind::PackageFullHash => PACKAGE_FULL_HASH_TEMPLATE_NAME,
             TemplateKind::MonorepoDefault => MONOREPO_DEFAULT_TEMPLATE_NAME,
             TemplateKind::MonorepoRemote => MONOREPO_REMOTE_TEMPLATE_NAME,
             TemplateKind::MonorepoFullHash => MONOREPO_FULL_HASH_TEMPLATE_NAME,
+            TemplateKind::MonorepoCompact => MONOREPO_COMPACT_TEMPLATE_NAME,
             TemplateKind::Custom(_) => "custom_template",
         }
     }
+
+    /// Which tag prefixes are relevant to the releases rendered by the template.
+    pub(crate) const fn tag_display_context(&self) -> TagDisplayContext {
+        match self {
+            TemplateKind::PackageDefault
+            | TemplateKind::PackageFullHash
+            | TemplateKind::PackageRemote => TagDisplayContext::Package,
+            _ => TagDisplayContext::Global,
+        }
+    }
+
+    /// Where the template places the short commit hash when no position is configured.
+    pub(crate) const fn default_hash_position(&self) -> HashPosition {
+        match self {
+            TemplateKind::FullHash
+            | TemplateKind::PackageFullHash
+            | TemplateKind::MonorepoFullHash => HashPosition::Leading,
+            TemplateKind::Flat => HashPosition::Hidden,
+            _ => HashPosition::Trailing,
+        }
+    }
 }
 
 /// A wrapper to append remote repository information to template context
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RemoteContext {
     remote: String,
     repository: String,
     owner: String,
+    kind: RemoteKind,
+    commit_url_format: Option<String>,
+    compare_url_format: Option<String>,
+    compare_separator: Option<String>,
+}
+
+/// The git hosting platform of a [`RemoteContext`], deciding the layout of the generated urls.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum RemoteKind {
+    #[default]
+    GitHub,
+    GitLab,
+    Gitea,
+    Bitbucket,
 }
 
 #[derive(Debug)]
@@ -132,14 +269,47 @@ pub struct MonoRepoContext<'a> {
 #[derive(Debug, Serialize)]
 pub struct PackageBumpContext<'a> {
     pub package_name: &'a str,
+    #[serde(serialize_with = "crate::conventional::changelog::serde::serialize_url_path")]
     pub package_path: &'a str,
     pub version: OidOf,
     pub from: Option<OidOf>,
 }
 
+/// A bumped package of a monorepo release, as listed by the monorepo templates.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct PackageBumpSummary {
+    pub name: String,
+    pub path: String,
+    pub from: Option<String>,
+    pub to: String,
+    pub locked: bool,
+}
+
+impl MonoRepoContext<'_> {
+    /// The package bumps rendered by the monorepo templates, in typed form for release automation.
+    pub fn package_summaries(&self) -> Vec<PackageBumpSummary> {
+        self.packages
+            .iter()
+            .map(|package| PackageBumpSummary {
+                name: package.package_name.to_string(),
+                path: package.package_path.replace('\\', "/"),
+                from: package.from.as_ref().map(ToString::to_string),
+                to: package.version.to_string(),
+                locked: self.package_lock,
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug)]
 pub struct PackageContext<'a> {
     pub package_name: &'a str,
+    /// Only render the commits with one of these scopes, see [`MonoRepoPackage::scope_filter`].
+    ///
+    /// [`MonoRepoPackage::scope_filter`]: crate::settings::MonoRepoPackage::scope_filter
+    pub scope_filter: Option<Vec<String>>,
+    /// Render the commits without scope along with the filtered ones.
+    pub include_unscoped: bool,
 }
 
 pub(crate) trait ToContext {
@@ -166,18 +336,136 @@ impl<'a> ToContext for PackageContext<'a> {
 impl ToContext for RemoteContext {
     fn to_context(&self) -> Context {
         let mut context = tera::Context::new();
-        context.insert("platform", &format!("https://{}", self.remote.as_str()));
+        context.insert("platform", &self.platform());
         context.insert("owner", self.owner.as_str());
-        context.insert(
-            "repository_url",
-            &format!("https://{}/{}/{}", self.remote, self.owner, self.repository),
-        );
+        context.insert("repository_url", &self.repository_url());
+        context.insert("compare_separator", self.compare_separator());
+        context.insert("commit_base_url", &self.commit_base_url());
+        context.insert("compare_base_url", &self.compare_base_url());
+        context.insert("user_base_url", &self.user_base_url());
+        context.insert("tree_base_url", &self.tree_base_url());
 
         context
     }
 }
 
 impl RemoteContext {
+    pub(crate) fn platform(&self) -> String {
+        format!("https://{}", self.remote)
+    }
+
+    /// Set the hosting platform, GitHub by default.
+    pub fn with_kind(mut self, kind: RemoteKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Override the commit and compare urls of the hosting platform with format strings
+    /// (`{sha}`, `{from}`, `{to}`, `{owner}` and `{repo}` are replaced).
+    pub fn with_url_formats(
+        mut self,
+        commit_url_format: Option<String>,
+        compare_url_format: Option<String>,
+    ) -> Self {
+        self.commit_url_format = commit_url_format;
+        self.compare_url_format = compare_url_format;
+        self
+    }
+
+    /// Override the separator between the two revisions of the compare urls.
+    pub fn with_compare_separator(mut self, compare_separator: Option<String>) -> Self {
+        self.compare_separator = compare_separator;
+        self
+    }
+
+    fn format_url(&self, format: &str) -> String {
+        format
+            .replace("{owner}", &self.owner)
+            .replace("{repo}", &self.repository)
+    }
+
+    /// Url of the given commit.
+    pub(crate) fn commit_url(&self, sha: &str) -> String {
+        match &self.commit_url_format {
+            Some(format) => self.format_url(format).replace("{sha}", sha),
+            None => format!("{}{sha}", self.commit_base_url()),
+        }
+    }
+
+    /// Url comparing `from` to `to`, Bitbucket lists the newest revision first.
+    pub(crate) fn compare_url(&self, from: &str, to: &str) -> String {
+        let separator = self.compare_separator();
+        match (&self.compare_url_format, self.kind) {
+            (Some(format), _) => self
+                .format_url(format)
+                .replace("{from}", from)
+                .replace("{to}", to),
+            (None, RemoteKind::Bitbucket) => {
+                format!("{}{to}{separator}{from}", self.compare_base_url())
+            }
+            (None, _) => format!("{}{from}{separator}{to}", self.compare_base_url()),
+        }
+    }
+
+    /// Url listing the history of the repository up to `to`, used in place of a compare url
+    /// for the first release of a repository.
+    pub(crate) fn history_url(&self, to: &str) -> String {
+        match self.kind {
+            RemoteKind::GitHub | RemoteKind::Gitea => {
+                format!("{}/commits/{to}", self.repository_url())
+            }
+            RemoteKind::GitLab => format!("{}/-/commits/{to}", self.repository_url()),
+            RemoteKind::Bitbucket => format!("{}/commits/tag/{to}", self.repository_url()),
+        }
+    }
+
+    fn repository_url(&self) -> String {
+        format!("https://{}/{}/{}", self.remote, self.owner, self.repository)
+    }
+
+    /// Url of a commit once its sha is appended.
+    pub(crate) fn commit_base_url(&self) -> String {
+        match self.kind {
+            RemoteKind::GitHub | RemoteKind::Gitea => format!("{}/commit/", self.repository_url()),
+            RemoteKind::GitLab => format!("{}/-/commit/", self.repository_url()),
+            RemoteKind::Bitbucket => format!("{}/commits/", self.repository_url()),
+        }
+    }
+
+    /// Url of a revision range once `<from><separator><to>` is appended.
+    pub(crate) fn compare_base_url(&self) -> String {
+        match self.kind {
+            RemoteKind::GitHub | RemoteKind::Gitea => format!("{}/compare/", self.repository_url()),
+            RemoteKind::GitLab => format!("{}/-/compare/", self.repository_url()),
+            RemoteKind::Bitbucket => format!("{}/branches/compare/", self.repository_url()),
+        }
+    }
+
+    /// Url of a user profile once the username is appended.
+    pub(crate) fn user_base_url(&self) -> String {
+        format!("{}/", self.platform())
+    }
+
+    /// Url of the repository files at a revision once the revision is appended.
+    pub(crate) fn tree_base_url(&self) -> String {
+        match self.kind {
+            RemoteKind::GitHub | RemoteKind::GitLab | RemoteKind::Gitea => {
+                format!("{}/tree/", self.repository_url())
+            }
+            RemoteKind::Bitbucket => format!("{}/src/", self.repository_url()),
+        }
+    }
+
+    /// Separator between the two revisions of a compare url, GitLab expects `...`
+    /// unless another one is configured.
+    pub(crate) fn compare_separator(&self) -> &str {
+        match (&self.compare_separator, self.kind) {
+            (Some(separator), _) => separator,
+            (None, RemoteKind::GitLab) => "...",
+            (None, _) => "..",
+        }
+    }
+
     pub fn try_new(
         remote: Option<String>,
         repository: Option<String>,
@@ -188,9 +476,86 @@ impl RemoteContext {
                 remote,
                 repository,
                 owner,
+                kind: RemoteKind::default(),
+                commit_url_format: None,
+                compare_url_format: None,
+                compare_separator: None,
             }),
             (None, None, None) => None,
             _ => panic!("Changelog remote context should be set. Missing one of 'remote', 'repository', 'owner' in changelog configuration")
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::conventional::changelog::error::ChangelogError;
+    use crate::conventional::changelog::template::{
+        RemoteContext, Template, TemplateKind, ToContext,
+    };
+    use std::fs;
+
+    #[test]
+    fn should_load_custom_template_with_file_prefix() -> anyhow::Result<()> {
+        // Arrange
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("remote");
+        fs::write(&path, "## {{ version.tag }}")?;
+
+        // Act
+        let template = Template::from_arg(&format!("file:{}", path.display()), None)?;
+
+        // Assert
+        assert!(matches!(template.kind, TemplateKind::Custom(custom) if custom == path));
+        Ok(())
+    }
+
+    #[test]
+    fn should_fail_on_missing_template_file() {
+        // Act
+        let result = Template::from_arg("file:does/not/exist.tera", None);
+
+        // Assert
+        assert!(matches!(result, Err(ChangelogError::TemplateNotFound(_))));
+    }
+
+    #[test]
+    fn should_fail_on_invalid_template_file() -> anyhow::Result<()> {
+        // Arrange
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("broken.tera");
+        fs::write(&path, "{% for commit in commits %}")?;
+
+        // Act
+        let result = Template::from_file(path, None);
+
+        // Assert
+        assert!(matches!(result, Err(ChangelogError::InvalidTemplate(_, _))));
+        Ok(())
+    }
+
+    #[test]
+    fn should_map_bitbucket_templates_to_the_remote_templates() -> anyhow::Result<()> {
+        // Arrange
+        let context = RemoteContext::try_new(
+            Some("bitbucket.org".to_string()),
+            Some("cocogitto".to_string()),
+            Some("cocogitto".to_string()),
+        );
+
+        // Act
+        let template = Template::from_arg("bitbucket_monorepo_remote", context)?;
+
+        // Assert
+        assert!(matches!(template.kind, TemplateKind::MonorepoRemote));
+        let context = template
+            .remote_context
+            .expect("remote context")
+            .to_context();
+        assert_eq!(
+            context.get("tree_base_url").and_then(|url| url.as_str()),
+            Some("https://bitbucket.org/cocogitto/cocogitto/src/")
+        );
+        Ok(())
+    }
+}
@@ -5,12 +5,17 @@ pub use crate::conventional::error::ConventionalCommitError;
 use crate::SETTINGS;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use colored::*;
-use conventional_commit_parser::commit::ConventionalCommit;
+use conventional_commit_parser::commit::{CommitType, ConventionalCommit};
+use conventional_commit_parser::error::ParseError;
 use git2::Commit as Git2Commit;
 use log::info;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Eq, PartialEq)]
+/// Commit type, and changelog section, of the commits not following the conventional commit spec
+/// when `changelog.include_non_conventional` is enabled.
+pub(crate) const NON_CONVENTIONAL_TYPE: &str = "Other Changes";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Commit {
     pub oid: String,
     pub conventional: ConventionalCommit,
@@ -32,6 +37,9 @@ pub struct CommitConfig {
     /// Allow for this commit type to bump the patch version.
     #[serde(default)]
     pub bump_patch: bool,
+    /// Prefixed to the changelog title in the section headings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
 }
 
 impl CommitConfig {
@@ -41,6 +49,7 @@ impl CommitConfig {
             omit_from_changelog: false,
             bump_minor: false,
             bump_patch: false,
+            emoji: None,
         }
     }
 
@@ -70,7 +79,7 @@ impl Commit {
         let author = commit.author().name().unwrap_or("").to_string();
 
         let message = git2_message.trim_end().trim_start();
-        let conventional_commit = conventional_commit_parser::parse(message);
+        let conventional_commit = parse_message(message);
 
         match conventional_commit {
             Ok(message) => {
@@ -101,12 +110,34 @@ impl Commit {
                     oid,
                     summary,
                     author,
-                    cause,
+                    cause: *cause,
                 }))
             }
         }
     }
 
+    /// Wrap a commit that does not follow the conventional commit spec, its first message line
+    /// is used as summary and its type is the [`NON_CONVENTIONAL_TYPE`] section.
+    pub(crate) fn from_non_conventional_git_commit(commit: &Git2Commit) -> Self {
+        let summary = commit.summary().unwrap_or_default().trim().to_string();
+
+        Commit {
+            oid: commit.id().to_string(),
+            conventional: ConventionalCommit {
+                commit_type: CommitType::Custom(NON_CONVENTIONAL_TYPE.to_string()),
+                scope: None,
+                summary,
+                body: None,
+                footers: vec![],
+                is_breaking_change: false,
+            },
+            author: commit.author().name().unwrap_or("").to_string(),
+            date: DateTime::from_timestamp(commit.time().seconds(), 0)
+                .expect("valid commit date")
+                .naive_utc(),
+        }
+    }
+
     pub(crate) fn shorthand(&self) -> &str {
         if self.oid != "not committed" {
             &self.oid[0..6]
@@ -122,6 +153,16 @@ impl Commit {
             .map_or(false, |config| config.omit_from_changelog)
     }
 
+    /// Content of the commit changelog footer (see `changelog.changelog_footer_token`), if any.
+    pub(crate) fn changelog_footer(&self) -> Option<&str> {
+        let token = &SETTINGS.changelog.changelog_footer_token;
+        self.conventional
+            .footers
+            .iter()
+            .find(|footer| footer.token.eq_ignore_ascii_case(token))
+            .map(|footer| footer.content.trim())
+    }
+
     pub(crate) fn is_major_bump(&self) -> bool {
         self.conventional.is_breaking_change
     }
@@ -263,7 +304,7 @@ pub fn verify(
         return Ok(());
     }
 
-    let commit = conventional_commit_parser::parse(msg);
+    let commit = parse_message(msg);
 
     match commit {
         Ok(commit) => match &SETTINGS.commit_types().get(&commit.commit_type) {
@@ -286,8 +327,51 @@ pub fn verify(
                 author: author.unwrap_or_else(|| "Unknown".to_string()),
             })),
         },
-        Err(err) => Err(Box::new(ConventionalCommitError::ParseError(err))),
+        Err(err) => Err(Box::new(ConventionalCommitError::ParseError(*err))),
+    }
+}
+
+/// Parse a conventional commit message, scopes may contain balanced parentheses
+/// (e.g. `feat(api(v2)): add endpoint`).
+pub fn parse_message(message: &str) -> Result<ConventionalCommit, Box<ParseError>> {
+    let header = message.lines().next().unwrap_or_default();
+    let Some(open) = header
+        .find('(')
+        .filter(|open| !header[..*open].contains(':'))
+    else {
+        return conventional_commit_parser::parse(message).map_err(Box::new);
+    };
+
+    let mut depth = 0;
+    let mut close = None;
+    for (idx, char) in header[open..].char_indices() {
+        match char {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(open + idx);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Report the unclosed scope rather than letting the parser split it on an inner paren
+    let Some(close) = close else {
+        return conventional_commit_parser::parse(&header[..=open]).map_err(Box::new);
+    };
+
+    let scope = &header[open + 1..close];
+    if !scope.contains('(') {
+        return conventional_commit_parser::parse(message).map_err(Box::new);
     }
+
+    let message = format!("{}scope{}", &message[..=open], &message[close..]);
+    let mut commit = conventional_commit_parser::parse(&message).map_err(Box::new)?;
+    commit.scope = Some(scope.to_string());
+    Ok(commit)
 }
 
 pub(crate) fn format_summary(commit: &ConventionalCommit) -> String {
@@ -301,7 +385,9 @@ pub(crate) fn format_summary(commit: &ConventionalCommit) -> String {
 
 #[cfg(test)]
 mod test {
-    use crate::conventional::commit::{format_summary, verify, Commit, CommitConfig};
+    use crate::conventional::commit::{
+        format_summary, parse_message, verify, Commit, CommitConfig,
+    };
 
     use crate::test_helpers::{commit, git_init_no_gpg};
     use crate::Repository;
@@ -574,4 +660,32 @@ mod test {
         assert_that!(commit).is_err();
         Ok(())
     }
+
+    #[test]
+    fn should_parse_scope_with_nested_parentheses() {
+        // Arrange
+        let message = "feat(api(v2))!: add endpoint";
+
+        // Act
+        let commit = parse_message(message);
+
+        // Assert
+        let commit = commit.unwrap();
+        assert_that!(commit.commit_type).is_equal_to(CommitType::Feature);
+        assert_that!(commit.scope).is_equal_to(Some("api(v2)".to_owned()));
+        assert_that!(commit.summary).is_equal_to("add endpoint".to_owned());
+        assert_that!(commit.is_breaking_change).is_true();
+    }
+
+    #[test]
+    fn should_fail_to_parse_scope_with_unbalanced_parentheses() {
+        // Arrange
+        let message = "feat(api(v2): add endpoint";
+
+        // Act
+        let commit = parse_message(message);
+
+        // Assert
+        assert_that!(commit).is_err();
+    }
 }
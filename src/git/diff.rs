@@ -1,7 +1,55 @@
+use std::collections::HashSet;
+
+use crate::git::error::Git2Error;
 use crate::git::repository::Repository;
-use git2::{Diff, DiffOptions, Object};
+use git2::{Diff, DiffOptions, Object, Oid};
+use serde::Serialize;
+
+/// Changes introduced by a set of commits.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize)]
+pub struct DiffStat {
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+    pub commits: usize,
+}
 
 impl Repository {
+    /// Sum the diffs of the given commits against their first parent,
+    /// files changed by several commits are counted once.
+    pub(crate) fn diff_stat<'a>(
+        &self,
+        commits: impl IntoIterator<Item = &'a str>,
+    ) -> Result<DiffStat, Git2Error> {
+        let mut stat = DiffStat::default();
+        let mut files = HashSet::new();
+
+        for oid in commits {
+            let commit = self.0.find_commit(Oid::from_str(oid)?)?;
+            let parent_tree = match commit.parent(0) {
+                Ok(parent) => Some(parent.tree()?),
+                Err(_) => None,
+            };
+
+            let diff =
+                self.0
+                    .diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree()?), None)?;
+            let diff_stats = diff.stats()?;
+            stat.insertions += diff_stats.insertions();
+            stat.deletions += diff_stats.deletions();
+            stat.commits += 1;
+
+            for delta in diff.deltas() {
+                if let Some(path) = delta.new_file().path().or(delta.old_file().path()) {
+                    files.insert(path.to_path_buf());
+                }
+            }
+        }
+
+        stat.files_changed = files.len();
+        Ok(stat)
+    }
+
     pub(crate) fn get_diff(&self, include_untracked: bool) -> Option<Diff> {
         let mut options = DiffOptions::new();
         options.include_untracked(include_untracked);
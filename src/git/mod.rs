@@ -6,6 +6,7 @@ pub mod monorepo;
 pub mod oid;
 pub mod repository;
 pub mod rev;
+pub mod signature;
 pub mod stash;
 pub mod status;
 pub mod tag;
@@ -13,6 +13,15 @@ pub enum OidOf {
     FirstCommit(Oid),
 }
 
+/// Where a tag is displayed, deciding which of its prefixes are relevant
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TagDisplayContext {
+    /// Repository wide changelog, the global `tag_prefix` is stripped
+    Global,
+    /// Package changelog, only the package local version is kept
+    Package,
+}
+
 impl OidOf {
     pub fn oid(&self) -> &Oid {
         match self {
@@ -20,6 +29,19 @@ impl OidOf {
             OidOf::Head(o) | OidOf::Other(o) | OidOf::FirstCommit(o) => o,
         }
     }
+
+    /// Print the oid like [`Display`], stripping the tag prefixes irrelevant to the given context.
+    pub fn display_for(&self, context: TagDisplayContext) -> String {
+        match (self, context) {
+            (OidOf::Tag(tag), TagDisplayContext::Package) => tag.version.to_string(),
+            (OidOf::Tag(tag), TagDisplayContext::Global) => Tag {
+                prefix: None,
+                ..tag.clone()
+            }
+            .to_string(),
+            _ => self.to_string(),
+        }
+    }
 }
 
 impl Display for OidOf {
@@ -33,3 +55,46 @@ impl Display for OidOf {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use semver::Version;
+    use speculoos::prelude::*;
+
+    use crate::git::oid::{OidOf, TagDisplayContext};
+    use crate::git::tag::Tag;
+
+    fn tag(package: Option<&str>) -> OidOf {
+        OidOf::Tag(Tag {
+            package: package.map(str::to_string),
+            prefix: Some("v".to_string()),
+            version: Version::new(1, 0, 0),
+            oid: None,
+            target: None,
+        })
+    }
+
+    #[test]
+    fn should_strip_global_prefix_of_global_monorepo_tag() {
+        // Arrange
+        let tag = tag(None);
+
+        // Act
+        let display = tag.display_for(TagDisplayContext::Global);
+
+        // Assert
+        assert_that!(display).is_equal_to("1.0.0".to_string());
+    }
+
+    #[test]
+    fn should_display_package_local_version_of_package_tag() {
+        // Arrange
+        let tag = tag(Some("one"));
+
+        // Act
+        let display = tag.display_for(TagDisplayContext::Package);
+
+        // Assert
+        assert_that!(display).is_equal_to("1.0.0".to_string());
+    }
+}
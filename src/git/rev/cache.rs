@@ -1,14 +1,28 @@
+use crate::conventional::commit::{Commit, ConventionalCommitError};
 use crate::git::error::TagError;
 use crate::git::oid::OidOf;
 use crate::git::repository::Repository;
 use crate::git::tag::Tag;
+use git2::{Commit as Git2Commit, Oid};
 use once_cell::sync::{Lazy, OnceCell};
-use std::collections::BTreeMap;
+#[cfg(test)]
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex, MutexGuard};
 
 static REPO_CACHE: Lazy<Arc<Mutex<BTreeMap<String, OidOf>>>> =
     Lazy::new(|| Arc::new(Mutex::new(BTreeMap::new())));
 
+static TAG_MESSAGES: Lazy<Arc<Mutex<HashMap<String, String>>>> =
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
+// Names of the tags pointing at each commit
+type TagsByCommit = HashMap<Oid, Vec<String>>;
+
+static TAGS_BY_COMMIT: Lazy<Arc<Mutex<TagsByCommit>>> =
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
 static FIRST_COMMIT: OnceCell<OidOf> = OnceCell::new();
 
 pub(crate) fn get_cache(repository: &Repository) -> MutexGuard<'_, BTreeMap<String, OidOf>> {
@@ -36,6 +50,24 @@ pub(crate) fn get_cache(repository: &Repository) -> MutexGuard<'_, BTreeMap<Stri
             if let Some(target) = tag.target.as_ref() {
                 let target = target.to_string();
                 cache.insert(target, OidOf::Tag(tag.clone()));
+
+                let message = repository
+                    .0
+                    .find_tag(*tag.oid_unchecked())
+                    .ok()
+                    .and_then(|annotated| {
+                        annotated
+                            .message()
+                            .map(|msg| strip_signature(msg).trim().to_string())
+                    })
+                    .filter(|msg| !msg.is_empty());
+
+                if let Some(message) = message {
+                    TAG_MESSAGES
+                        .lock()
+                        .unwrap()
+                        .insert(tag.to_string(), message);
+                }
             }
 
             if let Some(oid) = tag.oid.as_ref() {
@@ -43,6 +75,14 @@ pub(crate) fn get_cache(repository: &Repository) -> MutexGuard<'_, BTreeMap<Stri
                 cache.insert(oid, OidOf::Tag(tag.clone()));
             }
 
+            if let Some(commit) = tag.target.or(tag.oid) {
+                let mut tags_by_commit = TAGS_BY_COMMIT.lock().unwrap();
+                let tags = tags_by_commit.entry(commit).or_default();
+                if !tags.contains(&tag.to_string()) {
+                    tags.push(tag.to_string());
+                }
+            }
+
             cache.insert(tag.to_string(), OidOf::Tag(tag));
         }
     }
@@ -50,6 +90,73 @@ pub(crate) fn get_cache(repository: &Repository) -> MutexGuard<'_, BTreeMap<Stri
     cache
 }
 
+// Signed tags carry their signature at the end of the message
+fn strip_signature(message: &str) -> &str {
+    [
+        "-----BEGIN PGP SIGNATURE-----",
+        "-----BEGIN SSH SIGNATURE-----",
+    ]
+    .iter()
+    .filter_map(|marker| message.find(marker))
+    .min()
+    .map_or(message, |start| &message[..start])
+}
+
+/// The message of an annotated tag, `None` for lightweight tags.
+pub(crate) fn tag_message(tag: &Tag) -> Option<String> {
+    TAG_MESSAGES.lock().unwrap().get(&tag.to_string()).cloned()
+}
+
+/// Names of the other tags pointing to the same commit as `tag`.
+pub(crate) fn tag_aliases(tag: &Tag) -> Vec<String> {
+    let Some(commit) = tag.target.or(tag.oid) else {
+        return vec![];
+    };
+
+    let name = tag.to_string();
+    TAGS_BY_COMMIT
+        .lock()
+        .unwrap()
+        .get(&commit)
+        .map(|tags| {
+            tags.iter()
+                .filter(|alias| **alias != name)
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Conventional commits parsed from git, by oid. Generating several changelogs over overlapping
+/// ranges with the same cache only parses each commit once.
+#[derive(Debug, Default)]
+pub(crate) struct CommitCache {
+    commits: RefCell<HashMap<Oid, Commit>>,
+    #[cfg(test)]
+    parses: Cell<usize>,
+}
+
+impl CommitCache {
+    /// Parse a git commit into a conventional [`Commit`], reusing the result of a previous parse
+    /// of the same oid.
+    pub(crate) fn parse(
+        &self,
+        commit: &Git2Commit,
+    ) -> Result<Commit, Box<ConventionalCommitError>> {
+        if let Some(parsed) = self.commits.borrow().get(&commit.id()) {
+            return Ok(parsed.clone());
+        }
+
+        #[cfg(test)]
+        self.parses.set(self.parses.get() + 1);
+        let parsed = Commit::from_git_commit(commit)?;
+        self.commits
+            .borrow_mut()
+            .insert(commit.id(), parsed.clone());
+        Ok(parsed)
+    }
+}
+
 impl Repository {
     fn resolve_tag(&self, tag: &str) -> Result<Tag, TagError> {
         self.0
@@ -68,10 +175,17 @@ impl Repository {
 
 #[cfg(test)]
 mod test {
+    use crate::conventional::changelog::release::Release;
+    use crate::conventional::changelog::renderer::Renderer;
+    use crate::conventional::changelog::template::Template;
     use crate::git::repository::Repository;
-    use crate::git::rev::cache::get_cache;
-    use crate::test_helpers::git_init_no_gpg;
+    use crate::git::rev::cache::{get_cache, strip_signature, CommitCache};
+    use crate::settings::SameCommitTags;
+    use crate::test_helpers::{commit, git_init_no_gpg, git_tag};
+    use crate::CocoGitto;
+    use chrono::Utc;
     use cmd_lib::run_cmd;
+    use indoc::indoc;
     use sealed_test::prelude::*;
     use speculoos::prelude::*;
 
@@ -83,6 +197,48 @@ mod test {
         Ok(())
     }
 
+    #[sealed_test]
+    fn overlapping_releases_should_parse_commits_once() -> anyhow::Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        git_tag("0.1.0")?;
+        commit("feat: feature 2")?;
+        commit("fix: bug fix")?;
+
+        let cache = CommitCache::default();
+
+        // Act
+        Release::from_commits(repo.revwalk("..")?, Utc::now(), &cache)?;
+        Release::from_commits(repo.revwalk("0.1.0..")?, Utc::now(), &cache)?;
+        Release::from_commits(repo.revwalk("..0.1.0")?, Utc::now(), &cache)?;
+
+        // Assert
+        assert_that!(cache.parses.get()).is_equal_to(4);
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn multi_template_run_should_parse_commits_once() -> anyhow::Result<()> {
+        // Arrange
+        git_init_no_gpg()?;
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        git_tag("0.1.0")?;
+        commit("fix: bug fix")?;
+        let cog = CocoGitto::get()?;
+
+        // Act
+        for template in ["default", "full_hash", "flat"] {
+            cog.get_changelog_at_tag("..", Template::from_arg(template, None)?)?;
+        }
+
+        // Assert
+        assert_that!(cog.commit_cache.parses.get()).is_equal_to(3);
+        Ok(())
+    }
+
     #[sealed_test]
     fn resolve_annotated_tag_ok() -> anyhow::Result<()> {
         // Arrange
@@ -133,4 +289,98 @@ mod test {
         assert_that!(tag).is_err();
         Ok(())
     }
+
+    #[sealed_test]
+    fn annotated_tag_message_should_be_rendered_as_release_intro() -> anyhow::Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        run_cmd!(
+            git tag -a 1.0.0 -m "Highlights of the first release";
+        )?;
+        let release = Release::try_from(repo.revwalk("..1.0.0")?)?;
+        let mut renderer = Renderer::default().with_tag_message(true);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_that!(changelog).contains("\nHighlights of the first release\n\n#### Features\n");
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn lightweight_tag_should_have_no_message() -> anyhow::Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        git_tag("1.0.0")?;
+
+        // Act
+        let release = Release::try_from(repo.revwalk("..1.0.0")?)?;
+
+        // Assert
+        assert_that!(release.tag_message).is_none();
+        Ok(())
+    }
+
+    #[test]
+    fn should_strip_the_signature_of_signed_tag_messages() {
+        // Arrange
+        let message = indoc! {
+            "Highlights of the first release
+            -----BEGIN SSH SIGNATURE-----
+            U1NIU0lHAAAAAQAAADMAAAALc3NoLWVkMjU1MTkAAAAg
+            -----END SSH SIGNATURE-----
+            "
+        };
+
+        // Act
+        let message = strip_signature(message);
+
+        // Assert
+        assert_that!(message).is_equal_to("Highlights of the first release\n");
+    }
+
+    #[sealed_test]
+    fn tags_on_the_same_commit_should_be_merged_in_release_header() -> anyhow::Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        git_tag("0.1.0")?;
+        git_tag("0.1.1")?;
+        let release = Release::try_from(repo.revwalk("..0.1.1")?)?;
+        let mut renderer = Renderer::default().with_same_commit_tags(SameCommitTags::Merge);
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_that!(changelog).starts_with("## 0.1.1 / 0.1.0 - ");
+        assert_that!(changelog).does_not_contain("- - -");
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn tags_on_the_same_commit_should_render_a_single_release_by_default() -> anyhow::Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        git_tag("0.1.0")?;
+        git_tag("0.1.1")?;
+        let release = Release::try_from(repo.revwalk("..0.1.1")?)?;
+        let mut renderer = Renderer::default();
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_that!(changelog).starts_with("## 0.1.1 - ");
+        assert_that!(changelog).does_not_contain("0.1.0");
+        Ok(())
+    }
 }
@@ -5,6 +5,7 @@ use crate::git::oid::OidOf;
 use crate::git::repository::Repository;
 use crate::git::rev::filters::PackagePathFilter;
 use crate::git::rev::CommitIter;
+use crate::git::tag::Tag;
 use crate::SETTINGS;
 
 impl Repository {
@@ -20,6 +21,7 @@ impl Repository {
         let package_path_filter = PackagePathFilter::from_package(package);
 
         for (oid_of, commit) in commit_range.into_iter() {
+            // Merge commits, octopus merges included, are diffed against their first parent
             let parent = commit.parent(0).ok().map(|commit| commit.id().to_string());
 
             let parent_tree = self.tree_to_treeish(parent.as_ref())?;
@@ -140,13 +142,40 @@ impl Repository {
             commits.push((first_oid, first_commit));
         }
 
+        if !SETTINGS.changelog.ignore_non_semver_tags {
+            self.ensure_no_non_semver_tag(&commits)?;
+        }
+
         Ok(CommitIter(commits))
     }
+
+    /// Fail if one of the given commits is tagged with a tag that is not a valid version,
+    /// such tags are otherwise silently skipped and never used as release boundaries.
+    /// Tags pointing at a tree or a blob can't tag a commit of the range and are skipped.
+    fn ensure_no_non_semver_tag(&self, commits: &[(OidOf, Commit)]) -> Result<(), Git2Error> {
+        for name in self.0.tag_names(None)?.iter().flatten() {
+            let Err(err) = Tag::from_str(name, None, None) else {
+                continue;
+            };
+
+            let reference = self.0.resolve_reference_from_short_name(name)?;
+            let Ok(target) = reference.peel_to_commit().map(|commit| commit.id()) else {
+                continue;
+            };
+
+            if commits.iter().any(|(_, commit)| commit.id() == target) {
+                return Err(Git2Error::TagError(err));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
+    use std::fs;
     use std::path::PathBuf;
 
     use anyhow::Result;
@@ -159,8 +188,9 @@ mod test {
     use crate::conventional::changelog::release::Release;
     use crate::git::oid::OidOf;
     use crate::git::repository::Repository;
+    use crate::git::rev::CommitIter;
     use crate::git::tag::{Tag, TagLookUpOptions};
-    use crate::settings::{MonoRepoPackage, Settings};
+    use crate::settings::{Changelog, MonoRepoPackage, Settings};
     use crate::test_helpers::{commit, git_init_no_gpg, git_tag};
 
     const COCOGITTO_REPOSITORY: &str = env!("CARGO_MANIFEST_DIR");
@@ -210,6 +240,36 @@ mod test {
         Ok(())
     }
 
+    #[sealed_test]
+    fn should_get_range_for_a_single_release_oldest_first() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        fs::write("cog.toml", "[changelog]\ncommit_order = \"oldest_first\"\n")?;
+        let one = commit("chore: first commit")?;
+        let two = commit("feat: feature 1")?;
+        let three = commit("feat: feature 2")?;
+        git_tag("0.1.0")?;
+
+        let range = repo.revwalk("0.1.0")?;
+
+        // Act
+        let release = Release::try_from(range)?;
+
+        // Assert
+        assert_that!(release.version.oid()).is_equal_to(&Oid::from_str(&three)?);
+        assert_that!(release.from).is_equal_to(OidOf::FirstCommit(Oid::from_str(&one)?));
+
+        let expected_commits: Vec<String> = release
+            .commits
+            .into_iter()
+            .map(|commit| commit.commit.oid)
+            .collect();
+
+        assert_that!(expected_commits).is_equal_to(vec![one, two, three]);
+
+        Ok(())
+    }
+
     #[sealed_test]
     fn shoud_get_range_for_a_multiple_release() -> Result<()> {
         // Arrange
@@ -255,6 +315,102 @@ mod test {
         Ok(())
     }
 
+    #[sealed_test]
+    fn non_semver_tag_should_not_be_a_release_boundary() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        let one = commit("chore: first commit")?;
+        let two = commit("feat: feature 1")?;
+        git_tag("0.1.0")?;
+        let three = commit("feat: feature 2")?;
+        git_tag("nightly")?;
+        let four = commit("feat: feature 3")?;
+
+        let range = repo.revwalk("..")?;
+
+        // Act
+        let release = Release::try_from(range)?;
+
+        // Assert
+        assert_that!(release.previous)
+            .is_some()
+            .matches(|previous| {
+                let commits: Vec<String> = previous
+                    .commits
+                    .iter()
+                    .map(|commit| commit.commit.oid.clone())
+                    .collect();
+
+                previous.previous.is_none() && commits == [two.clone(), one.clone()]
+            });
+
+        let expected_commits: Vec<String> = release
+            .commits
+            .into_iter()
+            .map(|commit| commit.commit.oid)
+            .collect();
+
+        assert_that!(expected_commits).is_equal_to(vec![four, three]);
+
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn non_semver_tag_should_fail_when_not_ignored() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        let settings = Settings {
+            changelog: Changelog {
+                ignore_non_semver_tags: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let settings = toml::to_string(&settings)?;
+        run_cmd!(echo $settings > cog.toml;)?;
+
+        commit("chore: first commit")?;
+        git_tag("nightly")?;
+        commit("feat: feature 1")?;
+
+        // Act
+        let range = repo.revwalk("..");
+
+        // Assert
+        assert_that!(range).is_err();
+
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn non_semver_tag_of_a_tree_should_be_skipped_when_not_ignored() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        let settings = Settings {
+            changelog: Changelog {
+                ignore_non_semver_tags: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let settings = toml::to_string(&settings)?;
+        run_cmd!(echo $settings > cog.toml;)?;
+
+        commit("chore: first commit")?;
+        commit("feat: feature 1")?;
+        run_cmd!(git tag snapshot "HEAD^{tree}";)?;
+
+        // Act
+        let range = repo.revwalk("..");
+
+        // Assert
+        assert_that!(range).is_ok();
+
+        Ok(())
+    }
+
     #[test]
     fn get_release_range_integration_test() -> Result<()> {
         // Arrange
@@ -341,6 +497,69 @@ mod test {
         Ok(())
     }
 
+    #[sealed_test]
+    fn octopus_merge_should_be_diffed_against_its_first_parent() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        let mut packages = HashMap::new();
+        packages.insert(
+            "one".to_string(),
+            MonoRepoPackage {
+                path: PathBuf::from("one"),
+                ..Default::default()
+            },
+        );
+
+        let settings = Settings {
+            packages,
+            ..Default::default()
+        };
+
+        let settings = toml::to_string(&settings)?;
+
+        run_cmd!(
+            echo $settings > cog.toml;
+            git add .;
+            git commit -m "chore: First commit";
+            git checkout -q -b package;
+            mkdir one;
+            echo changes > one/file;
+            git add .;
+            git commit -m "feat: package one";
+            git checkout -q master;
+            git checkout -q -b global;
+            echo changes > global;
+            git add .;
+            git commit -m "feat: global change";
+            git checkout -q master;
+            git merge -q --no-ff -m "chore: octopus merge" package global;
+        )?;
+
+        // Act
+        let head = repo.get_head_commit()?;
+        let commit_range_package = repo.get_commit_range_for_package("..HEAD", "one")?;
+        let commit_range_global = repo.get_commit_range_for_monorepo_global("..HEAD")?;
+
+        // Assert
+        let messages = |range: &CommitIter| -> Vec<String> {
+            range
+                .iter_commits()
+                .filter_map(|commit| commit.summary().map(str::to_string))
+                .collect()
+        };
+
+        assert_that!(head.parent_count()).is_equal_to(3);
+        assert_that!(messages(&commit_range_package)).is_equal_to(vec![
+            "chore: octopus merge".to_string(),
+            "feat: package one".to_string(),
+        ]);
+        assert_that!(messages(&commit_range_global)).is_equal_to(vec![
+            "feat: global change".to_string(),
+            "chore: octopus merge".to_string(),
+        ]);
+        Ok(())
+    }
+
     #[sealed_test]
     fn package_with_changes_have_some_commits() -> Result<()> {
         let repo = init_mono_repo_for_range_filtering()?;
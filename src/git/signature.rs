@@ -0,0 +1,186 @@
+use git2::Commit as Git2Commit;
+
+const PGP_SIGNATURE_BEGIN: &str = "-----BEGIN PGP SIGNATURE-----";
+const PGP_SIGNATURE_END: &str = "-----END PGP SIGNATURE-----";
+
+// OpenPGP signature subpacket types (RFC 4880, section 5.2.3.1)
+const ISSUER_KEY_ID: u8 = 16;
+const ISSUER_FINGERPRINT: u8 = 33;
+
+/// A signature found in a commit `gpgsig` header.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CommitSignature {
+    /// Upper case hex issuer fingerprint, or issuer key id when the signature
+    /// does not embed the full fingerprint. `None` for non OpenPGP signatures.
+    pub key: Option<String>,
+}
+
+impl CommitSignature {
+    /// Read the signature of a commit, returns `None` for unsigned commits.
+    pub(crate) fn from_commit(commit: &Git2Commit) -> Option<Self> {
+        let header = commit.header_field_bytes("gpgsig").ok()?;
+        let armored = String::from_utf8_lossy(&header);
+
+        Some(CommitSignature {
+            key: pgp_issuer(&armored),
+        })
+    }
+
+    /// Whether the signing key is one of the given fingerprints or long key ids.
+    pub fn is_trusted_by<'a>(&self, trusted_keys: impl IntoIterator<Item = &'a String>) -> bool {
+        let Some(key) = &self.key else {
+            return false;
+        };
+
+        trusted_keys.into_iter().any(|trusted| {
+            let trusted: String = trusted
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+                .to_uppercase();
+
+            trusted == *key || (key.len() == 16 && trusted.ends_with(key.as_str()))
+        })
+    }
+}
+
+fn pgp_issuer(armored: &str) -> Option<String> {
+    let begin = armored.find(PGP_SIGNATURE_BEGIN)? + PGP_SIGNATURE_BEGIN.len();
+    let end = armored.find(PGP_SIGNATURE_END)?;
+
+    // Skip the armor headers (`Key: value`) and the trailing checksum line
+    let body: String = armored[begin..end]
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.contains(':') && !line.starts_with('='))
+        .collect();
+
+    let packet = decode_base64(&body)?;
+    let subpackets = signature_subpackets(&packet)?;
+    let issuer = subpackets
+        .iter()
+        .find(|(kind, data)| *kind == ISSUER_FINGERPRINT && data.len() > 1)
+        .map(|(_, data)| &data[1..])
+        .or_else(|| {
+            subpackets
+                .iter()
+                .find(|(kind, _)| *kind == ISSUER_KEY_ID)
+                .map(|(_, data)| *data)
+        })?;
+
+    Some(issuer.iter().map(|byte| format!("{byte:02X}")).collect())
+}
+
+// Hashed and unhashed subpackets of a v4 (or later) signature packet
+fn signature_subpackets(packet: &[u8]) -> Option<Vec<(u8, &[u8])>> {
+    let (&tag, rest) = packet.split_first()?;
+    let (tag, body) = if tag & 0x40 != 0 {
+        let (&len, rest) = rest.split_first()?;
+        match len {
+            0..=191 => (tag & 0x3f, rest),
+            192..=223 => (tag & 0x3f, rest.get(1..)?),
+            _ => (tag & 0x3f, rest.get(4..)?),
+        }
+    } else {
+        let length_bytes = [1, 2, 4, 0][(tag & 0x03) as usize];
+        ((tag >> 2) & 0x0f, rest.get(length_bytes..)?)
+    };
+
+    if tag != 2 || *body.first()? < 4 {
+        return None;
+    }
+
+    let mut subpackets = vec![];
+    let mut offset = 4;
+    for _ in 0..2 {
+        let len = u16::from_be_bytes([*body.get(offset)?, *body.get(offset + 1)?]) as usize;
+        let mut area = body.get(offset + 2..offset + 2 + len)?;
+        offset += 2 + len;
+
+        while let Some((&first, rest)) = area.split_first() {
+            let (len, rest) = match first {
+                0..=191 => (first as usize, rest),
+                192..=254 => (
+                    ((first as usize - 192) << 8) + *rest.first()? as usize + 192,
+                    rest.get(1..)?,
+                ),
+                255 => (
+                    u32::from_be_bytes(rest.get(..4)?.try_into().ok()?) as usize,
+                    rest.get(4..)?,
+                ),
+            };
+
+            let data = rest.get(..len)?;
+            let (&kind, data) = data.split_first()?;
+            subpackets.push((kind & 0x7f, data));
+            area = &rest[len..];
+        }
+    }
+
+    Some(subpackets)
+}
+
+fn decode_base64(input: &str) -> Option<Vec<u8>> {
+    let mut output = Vec::with_capacity(input.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for byte in input.bytes().take_while(|byte| *byte != b'=') {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+        }
+    }
+
+    Some(output)
+}
+
+#[cfg(test)]
+mod test {
+    use speculoos::prelude::*;
+
+    use crate::git::signature::{pgp_issuer, CommitSignature};
+
+    const SIGNATURE: &str = "-----BEGIN PGP SIGNATURE-----
+
+iHUEABYIAB0WIQQZhJ2VTmZhARSAV94akxwqOEuzzQUCatIyzAAKCRAakxwqOEuz
+zYWxAP9NjzcbhUBi3YybXJJD2QbA9oVA0ObK2HraZiCy9w0hPAEAjxiF2VFbWvEP
+uw3sneABol1yNXq7SG8huXQHkjF2lgI=
+=irTg
+-----END PGP SIGNATURE-----";
+
+    #[test]
+    fn should_read_issuer_fingerprint() {
+        let key = pgp_issuer(SIGNATURE);
+
+        assert_that!(key)
+            .is_some()
+            .is_equal_to("19849D954E666101148057DE1A931C2A384BB3CD".to_string());
+    }
+
+    #[test]
+    fn should_trust_signature_by_fingerprint_or_long_key_id() {
+        let signature = CommitSignature {
+            key: Some("19849D954E666101148057DE1A931C2A384BB3CD".to_string()),
+        };
+        let key_id = CommitSignature {
+            key: Some("1A931C2A384BB3CD".to_string()),
+        };
+        let trusted = vec!["1984 9D95 4E66 6101 1480  57de 1a93 1c2a 384b b3cd".to_string()];
+
+        assert_that!(signature.is_trusted_by(&trusted)).is_true();
+        assert_that!(key_id.is_trusted_by(&trusted)).is_true();
+        assert_that!(signature.is_trusted_by(&vec!["ABCD".to_string()])).is_false();
+    }
+}
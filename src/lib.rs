@@ -9,7 +9,7 @@ use conventional_commit_parser::commit::{CommitType, ConventionalCommit};
 use conventional_commit_parser::parse_footers;
 use once_cell::sync::Lazy;
 
-use conventional::commit::{Commit, CommitConfig};
+use conventional::commit::{parse_message, Commit, CommitConfig};
 use conventional::version::IncrementCommand;
 use error::BumpError;
 use git::repository::Repository;
@@ -17,11 +17,13 @@ use git::repository::Repository;
 use serde::{Deserialize, Serialize};
 use settings::Settings;
 
+use crate::clock::{Clock, SystemClock};
 use crate::git::error::{Git2Error, TagError};
-use crate::git::rev::cache::get_cache;
+use crate::git::rev::cache::{get_cache, CommitCache};
 
 use crate::git::tag::Tag;
 
+pub mod clock;
 pub mod command;
 pub mod conventional;
 pub mod error;
@@ -63,6 +65,8 @@ pub static COMMITS_METADATA: Lazy<HashMap<CommitType, CommitConfig>> =
 #[derive(Debug)]
 pub struct CocoGitto {
     repository: Repository,
+    clock: Box<dyn Clock>,
+    commit_cache: CommitCache,
 }
 
 pub enum CommitHook {
@@ -74,11 +78,20 @@ pub enum CommitHook {
 
 impl CocoGitto {
     pub fn get() -> Result<Self> {
+        Self::get_with_clock(SystemClock)
+    }
+
+    /// Same as [`CocoGitto::get`], dating releases with the given clock instead of the system one.
+    pub fn get_with_clock(clock: impl Clock + 'static) -> Result<Self> {
         let repository = Repository::open(&std::env::current_dir()?)?;
         let _settings = Settings::get(&repository)?;
         let _changelog_path = settings::changelog_path();
 
-        Ok(CocoGitto { repository })
+        Ok(CocoGitto {
+            repository,
+            clock: Box::new(clock),
+            commit_cache: CommitCache::default(),
+        })
     }
 
     pub fn get_committer(&self) -> Result<String, Git2Error> {
@@ -115,7 +128,7 @@ impl CocoGitto {
         .to_string();
 
         // Validate the message
-        conventional_commit_parser::parse(&conventional_message)?;
+        parse_message(&conventional_message)?;
 
         Ok(conventional_message)
     }
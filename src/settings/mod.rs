@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fmt::Formatter;
 use std::path::PathBuf;
@@ -11,9 +11,12 @@ use crate::conventional::changelog::error::ChangelogError;
 use crate::conventional::changelog::template::{RemoteContext, Template};
 use crate::hook::Hooks;
 use crate::settings::error::SettingError;
-use config::{Config, File, FileFormat};
+use chrono_tz::Tz;
+use config::{Config, ConfigError, File, FileFormat};
 use conventional_commit_parser::commit::CommitType;
-use serde::{Deserialize, Serialize};
+use regex::RegexSet;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
 use std::path::Path;
 
 pub(crate) type AuthorSettings = Vec<AuthorSetting>;
@@ -194,6 +197,10 @@ pub struct MonoRepoPackage {
     pub pre_bump_hooks: Option<Vec<String>>,
     /// Overrides `post_package_bump_hooks`
     pub post_bump_hooks: Option<Vec<String>>,
+    /// Only render the commits with one of these scopes in the package changelog
+    pub scope_filter: Option<Vec<String>>,
+    /// Render the commits without scope in the package changelog when `scope_filter` is set
+    pub include_unscoped: bool,
     /// Custom profile to override `pre_bump_hooks`, `post_bump_hooks`
     pub bump_profiles: HashMap<String, BumpProfile>,
 }
@@ -207,6 +214,8 @@ impl Default for &MonoRepoPackage {
             changelog_path: None,
             pre_bump_hooks: None,
             post_bump_hooks: None,
+            scope_filter: None,
+            include_unscoped: false,
             bump_profiles: Default::default(),
             public_api: true,
         });
@@ -224,6 +233,8 @@ impl Default for MonoRepoPackage {
             changelog_path: None,
             pre_bump_hooks: None,
             post_bump_hooks: None,
+            scope_filter: None,
+            include_unscoped: false,
             bump_profiles: Default::default(),
             public_api: true,
         }
@@ -249,6 +260,73 @@ pub struct Changelog {
     pub owner: Option<String>,
     pub repository: Option<String>,
     pub authors: AuthorSettings,
+    pub commit_url_template: Option<String>,
+    pub commit_url_format: Option<String>,
+    pub compare_url_format: Option<String>,
+    pub deployment_url_template: Option<String>,
+    pub date_format: String,
+    pub release_date_format: String,
+    pub type_order: TypeOrder,
+    pub section_order: Vec<String>,
+    pub section_mapping: Option<PathBuf>,
+    pub aggregated_footers: Vec<String>,
+    pub header_style: HeaderStyle,
+    pub heading_syntax: HeadingSyntax,
+    pub milestone_token: Option<String>,
+    pub commit_line_suffix: Option<String>,
+    pub ignore_non_semver_tags: bool,
+    pub author_style: Option<AuthorStyle>,
+    pub scope_format: ScopeFormat,
+    pub hash_position: Option<HashPosition>,
+    pub footer_style: FooterStyle,
+    pub summary_style: SummaryStyle,
+    pub trusted_signing_keys: Vec<String>,
+    pub author_allowlist: Vec<String>,
+    pub unreleased_compare_ref: Option<String>,
+    pub unreleased_label: String,
+    pub tag_message: bool,
+    pub exclude_summary_patterns: Vec<String>,
+    pub changelog_footer_token: String,
+    pub same_commit_tags: SameCommitTags,
+    pub thousands_separator: Option<String>,
+    pub flat_compare_links: bool,
+    pub dedup_releases: bool,
+    pub fold_pre_releases: bool,
+    pub commit_order: CommitOrder,
+    pub scoped_commits_first: bool,
+    pub compare_separator: Option<String>,
+    pub contributor_counts: bool,
+    pub toc: bool,
+    pub toc_max_level: usize,
+    pub package_heading_level: usize,
+    pub timezone: Option<String>,
+    pub merge_duplicate_sections: bool,
+    pub diff_stats: bool,
+    pub initial_version_label: Option<String>,
+    pub baseline: Option<String>,
+    pub scope_counts: bool,
+    pub activity_bar: bool,
+    pub strip_tag_prefixes: bool,
+    pub include_non_conventional: bool,
+    pub skip_merge_commits: bool,
+    pub branch_origin: bool,
+    pub revert_handling: RevertHandling,
+    pub breaking_change_scopes: bool,
+    pub breaking_change_grouping: BreakingChangeGrouping,
+    pub always_show_types: Vec<String>,
+    pub internal_types: Vec<String>,
+    pub empty_section_placeholder: Option<String>,
+    pub empty_release_placeholder: Option<String>,
+    pub insert_marker: String,
+    pub wrap_width: Option<usize>,
+    // A value unless set to a search url, keep it between the values and the tables
+    pub author_link_fallback: AuthorLinkFallback,
+    // Tables must come last for the settings to serialize as toml
+    pub type_abbreviations: HashMap<String, String>,
+    pub issue_trackers: HashMap<String, String>,
+    pub commit_labels: HashMap<String, Vec<String>>,
+    pub grouping: Grouping,
+    pub required_label: Option<RequiredLabel>,
 }
 
 impl Default for Changelog {
@@ -261,15 +339,365 @@ impl Default for Changelog {
             owner: None,
             repository: None,
             authors: vec![],
+            commit_url_template: None,
+            commit_url_format: None,
+            compare_url_format: None,
+            deployment_url_template: None,
+            date_format: "%Y-%m-%d".to_string(),
+            release_date_format: "%Y-%m-%d".to_string(),
+            type_order: TypeOrder::default(),
+            section_order: vec![],
+            section_mapping: None,
+            aggregated_footers: vec![],
+            header_style: HeaderStyle::default(),
+            heading_syntax: HeadingSyntax::default(),
+            milestone_token: None,
+            commit_line_suffix: None,
+            ignore_non_semver_tags: true,
+            author_style: None,
+            scope_format: ScopeFormat::default(),
+            hash_position: None,
+            footer_style: FooterStyle::default(),
+            summary_style: SummaryStyle::default(),
+            trusted_signing_keys: vec![],
+            author_allowlist: vec![],
+            unreleased_compare_ref: None,
+            unreleased_label: "Unreleased".to_string(),
+            tag_message: false,
+            exclude_summary_patterns: vec![],
+            changelog_footer_token: "Changelog".to_string(),
+            same_commit_tags: SameCommitTags::default(),
+            thousands_separator: None,
+            flat_compare_links: false,
+            dedup_releases: false,
+            fold_pre_releases: false,
+            commit_order: CommitOrder::default(),
+            scoped_commits_first: false,
+            compare_separator: None,
+            contributor_counts: false,
+            toc: false,
+            toc_max_level: 2,
+            package_heading_level: 3,
+            timezone: None,
+            merge_duplicate_sections: true,
+            diff_stats: false,
+            initial_version_label: None,
+            baseline: None,
+            scope_counts: false,
+            activity_bar: false,
+            strip_tag_prefixes: false,
+            include_non_conventional: false,
+            skip_merge_commits: false,
+            branch_origin: false,
+            revert_handling: RevertHandling::default(),
+            breaking_change_scopes: false,
+            breaking_change_grouping: BreakingChangeGrouping::default(),
+            always_show_types: vec![],
+            internal_types: vec![],
+            empty_section_placeholder: None,
+            empty_release_placeholder: Some("- _no notable changes_".to_string()),
+            insert_marker: "<!-- cog:insert -->".to_string(),
+            wrap_width: None,
+            type_abbreviations: HashMap::new(),
+            issue_trackers: HashMap::new(),
+            commit_labels: HashMap::new(),
+            grouping: Grouping::default(),
+            required_label: None,
+            author_link_fallback: AuthorLinkFallback::default(),
         }
     }
 }
 
+/// Order of the commit type sections in a rendered release.
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TypeOrder {
+    /// Sections sorted by their changelog title.
+    #[default]
+    Alphabetical,
+    /// Sections with the most commits first, ties keep their [`TypeOrder::Alphabetical`] order.
+    ByCount,
+}
+
+/// Order of the commits within a release, releases are always listed from the latest.
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitOrder {
+    /// The latest commit first, as walked by git.
+    #[default]
+    NewestFirst,
+    /// The commits in the order they were made.
+    OldestFirst,
+}
+
+/// Prefix of the version header in a rendered release.
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum HeaderStyle {
+    /// `## 1.0.0 - 2015-09-05`
+    #[default]
+    Markdown,
+    /// `1.0.0 - 2015-09-05`
+    Plain,
+}
+
+/// Markdown syntax of the headings in a rendered release.
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum HeadingSyntax {
+    /// `## 1.0.0 - 2015-09-05`
+    #[default]
+    Atx,
+    /// `1.0.0 - 2015-09-05` underlined with `-`, only for level 1 and 2 headings.
+    Setext,
+}
+
+/// Normalization of the commit summaries in a rendered release.
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SummaryStyle {
+    /// `implement the changelog generator.`
+    #[default]
+    AsIs,
+    /// `Implement the changelog generator`
+    SentenceCase,
+    /// `Implement the changelog generator.`
+    SentenceCaseWithPeriod,
+}
+
+/// Position of the commit scope in a changelog entry.
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScopeFormat {
+    /// `**(parser)** implement the changelog generator`
+    #[default]
+    Leading,
+    /// `implement the changelog generator (parser)`
+    Trailing,
+}
+
+/// Position of the short commit hash in a changelog entry.
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum HashPosition {
+    /// `(1f2e3d4) - implement the changelog generator - @oknozor`
+    Leading,
+    /// `implement the changelog generator - (1f2e3d4) - @oknozor`
+    Trailing,
+    /// `implement the changelog generator - @oknozor`
+    Hidden,
+}
+
+/// Layout of the commit footers listed under each entry by the `default_with_footers` template.
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FooterStyle {
+    /// `  Refs: #42, Reviewed-by: Jane Doe`
+    Inline,
+    /// `  - Refs: #42`
+    #[default]
+    List,
+    /// `  **Refs**` followed by `  : #42`
+    DefinitionList,
+}
+
+/// How releases tagged on the same commit are rendered.
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SameCommitTags {
+    /// A single release named after the greatest tag, the other tags are not rendered.
+    #[default]
+    Skip,
+    /// A single release listing every tag name in its header.
+    Merge,
+}
+
+/// How a commit reverted within its own release is rendered, along with the reverting commit.
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RevertHandling {
+    /// Both commits are rendered.
+    #[default]
+    Keep,
+    /// Neither commit is rendered.
+    Drop,
+    /// `~~awesome feature~~`, the reverting commit is not rendered.
+    StrikeThrough,
+}
+
+/// How the breaking changes section of the monorepo templates is laid out.
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakingChangeGrouping {
+    /// A single list of breaking commits.
+    #[default]
+    Flat,
+    /// A sub heading per bumped package named by the commit scope,
+    /// other breaking commits are listed first.
+    Package,
+}
+
+/// How commits are grouped into the sections of a rendered release.
+#[derive(Debug, Deserialize, Serialize, Default, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields, default)]
+pub struct Grouping {
+    // A value, it must come before the key and fallback tables for the settings to serialize as toml
+    /// With the `author` key, authors with fewer commits in the release are grouped under `Others`.
+    pub min_commits_for_own_section: usize,
+    pub key: GroupBy,
+    pub fallback: GroupFallback,
+}
+
+/// Commit type sections loaded from the `changelog.section_mapping` file, in rendering order.
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SectionMapping {
+    pub sections: Vec<SectionMappingEntry>,
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SectionMappingEntry {
+    #[serde(rename = "type")]
+    pub commit_type: String,
+    /// Changelog title of the commit type, defaults to its configured title.
+    pub title: Option<String>,
+    /// Prefixed to the changelog title in the section headings.
+    pub emoji: Option<String>,
+    #[serde(default)]
+    pub omit: bool,
+}
+
+/// Footer label a commit must carry to be rendered.
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct RequiredLabel {
+    /// Token of the footer listing the commit labels, e.g. `Labels`.
+    pub token: String,
+    pub label: String,
+}
+
+/// The commit attribute a release is grouped by.
+#[derive(Debug, Deserialize, Default, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupBy {
+    /// One section per commit type.
+    #[default]
+    Type,
+    /// One section per commit scope.
+    Scope,
+    /// One section per commit author, see [`Grouping::min_commits_for_own_section`].
+    Author,
+    /// One section per content of the footer with the given token.
+    Footer(String),
+}
+
+/// Where the remote templates link the authors without a known username.
+#[derive(Debug, Deserialize, Default, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorLinkFallback {
+    /// Plain author name.
+    #[default]
+    None,
+    /// A `mailto:` link to the author email.
+    Mailto,
+    /// The given url, `{email}` and `{name}` are replaced with the author email and name.
+    Search(String),
+}
+
+/// Where commits without the grouping key go.
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupFallback {
+    /// A single section with the given name.
+    Bucket(String),
+    /// The section of their commit type.
+    Type,
+    /// Left out of the release.
+    Omit,
+    /// Directly under the release header, before any section heading.
+    Inline,
+}
+
+impl Default for GroupFallback {
+    fn default() -> Self {
+        GroupFallback::Bucket("Other".to_string())
+    }
+}
+
+// toml does not support newtype variants, serialize them as single entry tables
+impl Serialize for GroupBy {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            GroupBy::Type => serializer.serialize_str("type"),
+            GroupBy::Scope => serializer.serialize_str("scope"),
+            GroupBy::Author => serializer.serialize_str("author"),
+            GroupBy::Footer(token) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("footer", token)?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl Serialize for AuthorLinkFallback {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            AuthorLinkFallback::None => serializer.serialize_str("none"),
+            AuthorLinkFallback::Mailto => serializer.serialize_str("mailto"),
+            AuthorLinkFallback::Search(url) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("search", url)?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl Serialize for GroupFallback {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            GroupFallback::Bucket(name) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("bucket", name)?;
+                map.end()
+            }
+            GroupFallback::Type => serializer.serialize_str("type"),
+            GroupFallback::Omit => serializer.serialize_str("omit"),
+            GroupFallback::Inline => serializer.serialize_str("inline"),
+        }
+    }
+}
+
+/// How commit authors are displayed in a rendered release.
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorStyle {
+    /// The remote username, or the git signature when the username is unknown.
+    Handle,
+    /// The git signature.
+    Name,
+    /// The git signature followed by the remote username when it is known.
+    NameAndHandle,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct AuthorSetting {
     pub signature: String,
     pub username: String,
+    /// Profile URL of the author, used instead of the one built from the remote and username.
+    pub url: Option<String>,
+}
+
+pub fn commit_author_url(author: &str) -> Option<&'static str> {
+    SETTINGS
+        .changelog
+        .authors
+        .iter()
+        .find(|author_map| author_map.signature == author)
+        .and_then(|author| author.url.as_deref())
 }
 
 pub fn commit_username(author: &str) -> Option<&'static str> {
@@ -302,6 +730,70 @@ impl Settings {
         repository.try_into()
     }
 
+    // Override the commit type titles and omissions and the section order with the
+    // `changelog.section_mapping` file, relative to `root`
+    fn apply_section_mapping(mut self, root: &Path) -> Result<Self, SettingError> {
+        let Some(path) = &self.changelog.section_mapping else {
+            return Ok(self);
+        };
+
+        let mapping = Config::builder()
+            .add_source(File::from(root.join(path)))
+            .build()?
+            .try_deserialize::<SectionMapping>()?;
+
+        let invalid = |reason: String| {
+            ConfigError::Message(format!("invalid changelog.section_mapping: {reason}"))
+        };
+        let commit_types = self.commit_types();
+        let mut mapped = HashSet::new();
+        let mut section_order = vec![];
+        for entry in mapping.sections {
+            if !mapped.insert(entry.commit_type.clone()) {
+                return Err(invalid(format!("`{}` is mapped twice", entry.commit_type)).into());
+            }
+
+            let mut config = commit_types
+                .get(&CommitType::from(entry.commit_type.as_str()))
+                .cloned()
+                .unwrap_or_else(|| CommitConfig::new(&entry.commit_type));
+            if let Some(title) = entry.title {
+                if title.trim().is_empty() {
+                    return Err(
+                        invalid(format!("`{}` has an empty title", entry.commit_type)).into(),
+                    );
+                }
+                config.changelog_title = title;
+            }
+            config.emoji = entry.emoji.or(config.emoji);
+            config.omit_from_changelog = entry.omit;
+
+            if !config.omit_from_changelog {
+                section_order.push(config.changelog_title.clone());
+            }
+            self.commit_types
+                .insert(entry.commit_type, CommitConfigOrNull::CommitConfig(config));
+        }
+
+        self.changelog.section_order = section_order;
+        Ok(self)
+    }
+
+    // Reject settings that deserialize fine but can't be used
+    fn validate(self) -> Result<Self, SettingError> {
+        RegexSet::new(&self.changelog.exclude_summary_patterns).map_err(|err| {
+            ConfigError::Message(format!("invalid changelog.exclude_summary_patterns: {err}"))
+        })?;
+
+        if let Some(timezone) = &self.changelog.timezone {
+            timezone.parse::<Tz>().map_err(|err| {
+                ConfigError::Message(format!("invalid changelog.timezone: {err}"))
+            })?;
+        }
+
+        Ok(self)
+    }
+
     pub fn commit_types(&self) -> HashMap<CommitType, CommitConfig> {
         let commit_settings = self.commit_types.clone();
         let mut custom_types = HashMap::new();
@@ -379,7 +871,14 @@ impl Settings {
 
         let owner = self.changelog.owner.as_ref().cloned();
 
-        RemoteContext::try_new(remote, repository, owner)
+        RemoteContext::try_new(remote, repository, owner).map(|context| {
+            context
+                .with_url_formats(
+                    self.changelog.commit_url_format.clone(),
+                    self.changelog.compare_url_format.clone(),
+                )
+                .with_compare_separator(self.changelog.compare_separator.clone())
+        })
     }
 
     pub fn get_changelog_template(&self) -> Result<Template, ChangelogError> {
@@ -399,6 +898,9 @@ impl Settings {
 
         let template = match template {
             "remote" => "package_remote",
+            "gitlab_remote" => "gitlab_package_remote",
+            "gitea_remote" => "gitea_package_remote",
+            "bitbucket_remote" => "bitbucket_package_remote",
             "full_hash" => "package_full_hash",
             template => template,
         };
@@ -416,7 +918,11 @@ impl Settings {
 
         let template = match template {
             "remote" => "monorepo_remote",
+            "gitlab_remote" => "gitlab_monorepo_remote",
+            "gitea_remote" => "gitea_monorepo_remote",
+            "bitbucket_remote" => "bitbucket_monorepo_remote",
             "full_hash" => "monorepo_full_hash",
+            "compact" => "monorepo_compact",
             template => template,
         };
 
@@ -479,8 +985,10 @@ impl TryFrom<String> for Settings {
                 .add_source(File::from_str(&value, FileFormat::Toml))
                 .build()
                 .map_err(SettingError::from)?
-                .try_deserialize()
-                .map_err(SettingError::from)
+                .try_deserialize::<Settings>()
+                .map_err(SettingError::from)?
+                .apply_section_mapping(Path::new("."))?
+                .validate()
         }
     }
 }
@@ -497,8 +1005,10 @@ impl TryFrom<&Repository> for Settings {
                         .add_source(File::from(settings_path))
                         .build()
                         .map_err(SettingError::from)?
-                        .try_deserialize()
-                        .map_err(SettingError::from)
+                        .try_deserialize::<Settings>()
+                        .map_err(SettingError::from)?
+                        .apply_section_mapping(repo_path)?
+                        .validate()
                 } else {
                     Ok(Settings::default())
                 }
@@ -516,6 +1026,7 @@ mod test {
     use sealed_test::prelude::*;
     use speculoos::prelude::*;
 
+    use crate::settings::Settings;
     use crate::{test_helpers::git_init_no_gpg, COMMITS_METADATA};
 
     #[sealed_test]
@@ -530,4 +1041,50 @@ feat = {}
         assert_that!(COMMITS_METADATA.keys()).contains(&CommitType::BugFix);
         Ok(())
     }
+
+    #[test]
+    fn should_reject_invalid_exclude_summary_pattern() {
+        let settings = r#"
+[changelog]
+exclude_summary_patterns = ["^WIP", "[skip changelog"]
+"#;
+
+        let settings = Settings::try_from(settings.to_string());
+
+        assert_that!(settings).is_err();
+    }
+
+    #[sealed_test]
+    fn should_load_section_mapping_from_json() -> anyhow::Result<()> {
+        fs::write(
+            "sections.json",
+            r#"{ "sections": [{ "type": "fix", "title": "Fixes", "emoji": "🐛" }, { "type": "chore", "omit": true }] }"#,
+        )?;
+
+        let settings =
+            Settings::try_from("[changelog]\nsection_mapping = \"sections.json\"".to_string())?;
+
+        let commit_types = settings.commit_types();
+        assert_that!(settings.changelog.section_order).is_equal_to(vec!["Fixes".to_string()]);
+        assert_that!(commit_types[&CommitType::BugFix].changelog_title)
+            .is_equal_to("Fixes".to_string());
+        assert_that!(commit_types[&CommitType::BugFix].emoji).is_equal_to(Some("🐛".to_string()));
+        assert_that!(commit_types[&CommitType::BugFix].bump_patch).is_true();
+        assert_that!(commit_types[&CommitType::Chore].omit_from_changelog).is_true();
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_reject_section_mapping_with_duplicate_type() -> anyhow::Result<()> {
+        fs::write(
+            "sections.toml",
+            "[[sections]]\ntype = \"feat\"\n\n[[sections]]\ntype = \"feat\"\nomit = true\n",
+        )?;
+
+        let settings =
+            Settings::try_from("[changelog]\nsection_mapping = \"sections.toml\"".to_string());
+
+        assert_that!(settings).is_err();
+        Ok(())
+    }
 }
@@ -4,6 +4,10 @@ use cmd_lib::run_cmd;
 use sealed_test::prelude::*;
 use speculoos::prelude::*;
 
+use chrono::{TimeZone, Utc};
+use cocogitto::clock::FixedClock;
+use cocogitto::conventional::changelog::template::Template;
+use cocogitto::conventional::version::Increment;
 use cocogitto::CocoGitto;
 
 use crate::helpers::{git_commit, git_init, git_log_head_sha, git_tag};
@@ -107,3 +111,86 @@ fn from_commit_should_be_drained() -> Result<()> {
 
     Ok(())
 }
+
+#[sealed_test]
+fn preview_changelog_should_use_proposed_version_as_header() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature 2")?;
+
+    // Act
+    let cocogitto = CocoGitto::get()?;
+    let changelog = cocogitto.preview_changelog("1.1.0", Template::default())?;
+
+    // Assert
+    assert_that!(changelog).starts_with("## 1.1.0 - ");
+    assert_that!(changelog).contains("feature 2");
+    assert_that!(changelog).does_not_contain("feature 1");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn preview_release_should_compute_bump_and_render_notes() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.0.0")?;
+    git_commit("fix: bug fix")?;
+    git_commit("feat: feature 2")?;
+
+    // Act
+    let cocogitto = CocoGitto::get()?;
+    let (increment, changelog) = cocogitto.preview_release(Template::default())?;
+
+    // Assert
+    assert_that!(increment).is_equal_to(Increment::Minor);
+    assert_that!(changelog).starts_with("## 1.1.0 - ");
+    assert_that!(changelog).contains("feature 2");
+    assert_that!(changelog).contains("bug fix");
+    assert_that!(changelog).does_not_contain("feature 1");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn unreleased_changelog_should_be_dated_with_the_injected_clock() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat: feature 1")?;
+    let now = Utc.with_ymd_and_hms(2024, 3, 14, 15, 9, 26).unwrap();
+
+    // Act
+    let cocogitto = CocoGitto::get_with_clock(FixedClock(now))?;
+    let changelog = cocogitto.get_changelog("..", false)?;
+
+    // Assert
+    assert_that!(changelog.date).is_equal_to(now.naive_utc());
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_json_should_serialize_the_release_chain() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat: feature 1")?;
+    git_tag("0.1.0")?;
+    git_commit("fix: bug fix")?;
+    git_tag("0.2.0")?;
+    git_commit("fix: unreleased fix")?;
+
+    // Act
+    let cocogitto = CocoGitto::get()?;
+    let changelog = cocogitto.get_changelog_json("0.1.0..")?;
+
+    // Assert
+    assert_that!(changelog).starts_with("{\n  \"commits\": [\n");
+    assert_that!(changelog).contains("\"summary\": \"unreleased fix\"");
+    assert_that!(changelog).contains("\"previous\": {");
+    assert_that!(changelog).contains("\"tag\": \"0.2.0\"");
+    assert_that!(changelog).contains("\"summary\": \"bug fix\"");
+    Ok(())
+}